@@ -4,11 +4,11 @@ use tsp_solver::{Graph, TSPSolver};
 fn create_test_graph(size: usize) -> Graph {
     let mut matrix = vec![vec![0; size]; size];
     
-    for i in 0..size {
-        for j in 0..size {
+    for (i, row) in matrix.iter_mut().enumerate() {
+        for (j, cell) in row.iter_mut().enumerate() {
             if i != j {
                 let distance = ((i * 7 + j * 11) % 19) + 1;
-                matrix[i][j] = distance as i32;
+                *cell = distance as i32;
             }
         }
     }
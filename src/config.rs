@@ -1,31 +1,133 @@
 /// Libraries
+use crate::feasibility::FeasibilityReport;
+use crate::gtsp::CityCluster;
+use crate::pickup_delivery::DeliveryRequest;
+use crate::prize_collecting::CityReward;
+use crate::robust::{CostInterval, EdgeInterval};
+use crate::stochastic::{CostDistribution, EdgeDistribution};
+use crate::time_dependent::TimeSlot;
+use crate::time_windows::{CityTiming, TimeWindow};
+use crate::tsp_solver::RouteMode;
 use crate::{Graph, Result, TSPError};
 use std::fs;
 use std::path::Path;
 
+/// Block sections (a header line `NAME:` followed by its rows)
+const SECTIONS: &[&str] = &["MATRIX", "TIME_WINDOWS", "PRECEDENCE", "PICKUP_DELIVERY", "DEMANDS", "PRIZES", "CLUSTERS", "EDGES", "SCHEDULE", "DISTRIBUTIONS"];
+
+/// Single-line settings (`NAME: value`)
+const SETTINGS: &[&str] = &["CITIES:", "CAPACITY:"];
+
 pub struct ConfigReader;
 
 impl ConfigReader {
     /// Format:
-    /// ```
+    /// ```text
     /// # Komentar dimulai dengan #
     /// # Nama kota (opsional)
     /// CITIES: Kota_A, Kota_B, Kota_C, Kota_D
     /// 
-    /// # Matriks adjacency
+    /// # Matriks adjacency (- berarti tidak ada edge, bobot 0 dan negatif diperbolehkan,
+    /// # bawah..atas berarti biaya tidak pasti dalam interval tersebut)
     /// MATRIX:
     /// 0 10 15 20
-    /// 5  0  9 10
+    /// 5  0  8..12 -
     /// 6 13  0 12
     /// 8  8  9  0
+    ///
+    /// # Matriks bobot lain (opsional): MATRIX_<NAMA>, misalnya waktu tempuh
+    /// MATRIX_WAKTU:
+    /// 0 12 30 25
+    /// 6  0 11 -
+    /// 9 20  0 14
+    /// 10 9 12  0
+    ///
+    /// # Jendela waktu (opsional): kota: paling_awal paling_akhir [durasi_layanan]
+    /// TIME_WINDOWS:
+    /// Kota_B: 10 40 5
+    /// Kota_C: - - 3
+    ///
+    /// # Urutan wajib (opsional): kota_sebelum -> kota_sesudah
+    /// PRECEDENCE:
+    /// Kota_C -> Kota_B
+    ///
+    /// # Edge wajib/terlarang (opsional): kota_asal -> kota_tujuan: wajib|terlarang
+    /// EDGES:
+    /// Kota_B -> Kota_C: wajib
+    /// Kota_A -> Kota_D: terlarang
+    ///
+    /// # Pickup-delivery (opsional): kota_pickup -> kota_delivery: muatan
+    /// CAPACITY: 10
+    /// PICKUP_DELIVERY:
+    /// Kota_B -> Kota_D: 4
+    ///
+    /// # Permintaan CVRP (opsional, memakai CAPACITY): kota: permintaan
+    /// DEMANDS:
+    /// Kota_B: 3
+    /// Kota_C: 5
+    ///
+    /// # Hadiah dan penalti (opsional): kota: hadiah [penalti]
+    /// PRIZES:
+    /// Kota_B: 7 2
+    ///
+    /// # Cluster kota setara (opsional): nama_cluster: kota, kota, ...
+    /// CLUSTERS:
+    /// Cabang_B: Kota_B, Kota_D
+    ///
+    /// # Jadwal matriks waktu tempuh (opsional): waktu_mulai: nama_matriks
+    /// SCHEDULE:
+    /// 0: jarak
+    /// 30: waktu
+    ///
+    /// # Distribusi biaya edge (opsional): kota_asal -> kota_tujuan: normal rata2 simpangan
+    /// # | seragam bawah atas | empiris nilai nilai ...
+    /// DISTRIBUTIONS:
+    /// Kota_A -> Kota_B: normal 10 2.5
+    /// Kota_C -> Kota_D: empiris 9 10 12 30
     /// ```
     pub fn read_from_file<P: AsRef<Path>>(file_path: P) -> Result<Graph> {
-        let content = fs::read_to_string(&file_path)
+        Self::parse_content(&Self::read_section_file(file_path)?)
+    }
+
+    /// File content for `read_from_file` and the section readers below (each one parsed by its
+    /// `parse_*` counterpart)
+    fn read_section_file<P: AsRef<Path>>(file_path: P) -> Result<String> {
+        fs::read_to_string(&file_path)
             .map_err(|e| TSPError::ConfigError(
                 format!("Gagal membaca file {:?}: {}", file_path.as_ref(), e)
-            ))?;
+            ))
+    }
+
+    pub fn read_time_windows<P: AsRef<Path>>(file_path: P, graph: &Graph) -> Result<Vec<CityTiming>> {
+        Self::parse_time_windows(&Self::read_section_file(file_path)?, graph)
+    }
+
+    pub fn read_pickup_delivery<P: AsRef<Path>>(file_path: P, graph: &Graph) -> Result<Option<(Vec<DeliveryRequest>, i32)>> {
+        Self::parse_pickup_delivery(&Self::read_section_file(file_path)?, graph)
+    }
+
+    pub fn read_demands<P: AsRef<Path>>(file_path: P, graph: &Graph) -> Result<Option<(Vec<i32>, i32)>> {
+        Self::parse_demands(&Self::read_section_file(file_path)?, graph)
+    }
+
+    pub fn read_prizes<P: AsRef<Path>>(file_path: P, graph: &Graph) -> Result<Vec<CityReward>> {
+        Self::parse_prizes(&Self::read_section_file(file_path)?, graph)
+    }
+
+    pub fn read_clusters<P: AsRef<Path>>(file_path: P, graph: &Graph) -> Result<Vec<CityCluster>> {
+        Self::parse_clusters(&Self::read_section_file(file_path)?, graph)
+    }
 
-        Self::parse_content(&content)
+    pub fn read_schedule<P: AsRef<Path>>(file_path: P, graph: &Graph) -> Result<Vec<TimeSlot>> {
+        Self::parse_schedule(&Self::read_section_file(file_path)?, graph)
+    }
+
+    pub fn read_distributions<P: AsRef<Path>>(file_path: P, graph: &Graph) -> Result<Vec<EdgeDistribution>> {
+        Self::parse_distributions(&Self::read_section_file(file_path)?, graph)
+    }
+
+    pub fn read_cost_intervals<P: AsRef<Path>>(file_path: P) -> Result<Vec<EdgeInterval>> {
+        Self::parse_cost_intervals(&Self::read_section_file(file_path)?)
     }
 
     /// Parser
@@ -41,7 +143,7 @@ impl ConfigReader {
         }
 
         let mut city_names: Option<Vec<String>> = None;
-        let mut matrix: Option<Vec<Vec<i32>>> = None;
+        let mut matrix: Option<Vec<Vec<Option<CostInterval>>>> = None;
         let mut in_matrix_section = false;
 
         for line in lines {
@@ -53,9 +155,13 @@ impl ConfigReader {
                         .map(|s| s.trim().to_string())
                         .collect()
                 );
-            } else if line == "MATRIX:" {
-                in_matrix_section = true;
-                matrix = Some(Vec::new());
+            } else if Self::is_setting(line) {
+                continue;
+            } else if let Some(section) = Self::section_header(line)? {
+                in_matrix_section = section == "MATRIX";
+                if in_matrix_section {
+                    matrix = Some(Vec::new());
+                }
             } else if in_matrix_section {
                 if let Some(ref mut mat) = matrix {
                     let row = Self::parse_matrix_row(line)?;
@@ -72,19 +178,363 @@ impl ConfigReader {
             return Err(TSPError::ConfigError("Matrix tidak boleh kosong".to_string()));
         }
 
-        match city_names {
-            Some(names) => Graph::with_node_names(adjacency_matrix, names),
-            None => Graph::new(adjacency_matrix),
+        let mut graph = Graph::from_optional_matrix(adjacency_matrix.iter()
+            .map(|row| row.iter().map(|entry| entry.map(|interval| interval.midpoint())).collect())
+            .collect())?;
+        if let Some(names) = city_names {
+            graph.set_node_names(names)?;
+        }
+
+        for line in Self::section_lines(content, "PRECEDENCE")? {
+            let (before, after) = line.split_once("->").ok_or_else(|| TSPError::ConfigError(
+                format!("Format precedence salah (harus 'kota_sebelum -> kota_sesudah'): '{}'", line)
+            ))?;
+            let before = graph.find_node(before).map_err(|e| TSPError::ConfigError(e.to_string()))?;
+            let after = graph.find_node(after).map_err(|e| TSPError::ConfigError(e.to_string()))?;
+            graph.add_precedence(before, after)?;
+        }
+
+        for line in Self::section_lines(content, "EDGES")? {
+            let parsed = line.split_once("->").and_then(|(from, rest)| {
+                rest.split_once(':').map(|(to, kind)| (from, to, kind.trim()))
+            });
+            let (from, to, kind) = parsed.ok_or_else(|| TSPError::ConfigError(
+                format!("Format edge salah (harus 'kota_asal -> kota_tujuan: wajib|terlarang'): '{}'", line)
+            ))?;
+            let from = graph.find_node(from).map_err(|e| TSPError::ConfigError(e.to_string()))?;
+            let to = graph.find_node(to).map_err(|e| TSPError::ConfigError(e.to_string()))?;
+
+            match kind {
+                "wajib" => graph.require_edge(from, to)?,
+                "terlarang" => graph.forbid_edge(from, to)?,
+                _ => return Err(TSPError::ConfigError(
+                    format!("Jenis edge tidak dikenal: '{}' (gunakan wajib atau terlarang)", kind)
+                )),
+            }
+        }
+
+        for section in Self::weight_sections(content)? {
+            let matrix = Self::parse_weight_matrix(content, section, &graph)?;
+            graph.add_weight_matrix(&section["MATRIX_".len()..].to_lowercase(), matrix)?;
+        }
+
+        Ok(graph)
+    }
+
+    /// Headers of the named weight matrices (`MATRIX_WAKTU:` and the like), in file order
+    fn weight_sections(content: &str) -> Result<Vec<&str>> {
+        let mut sections = Vec::new();
+        for line in content.lines().map(|line| line.trim()) {
+            if let Some(name) = Self::section_header(line)?.filter(|name| name.starts_with("MATRIX_")) {
+                if !sections.contains(&name) {
+                    sections.push(name);
+                }
+            }
+        }
+        Ok(sections)
+    }
+
+    /// Rows of a named weight matrix; `-` is only allowed where the graph has no edge, intervals never
+    fn parse_weight_matrix(content: &str, section: &str, graph: &Graph) -> Result<Vec<Vec<i32>>> {
+        let mut matrix = Vec::new();
+        for (i, line) in Self::section_lines(content, section)?.into_iter().enumerate() {
+            let mut row = Vec::new();
+            for (j, weight) in Self::parse_matrix_row(line)?.into_iter().enumerate() {
+                match weight {
+                    Some(interval) if interval.low < interval.high => {
+                        return Err(TSPError::ConfigError(
+                            format!("{} tidak mendukung interval biaya ({}..{})", section, interval.low, interval.high)
+                        ));
+                    }
+                    Some(interval) => row.push(interval.low),
+                    None if i < graph.size && j < graph.size && graph.has_edge(i, j) => {
+                        return Err(TSPError::ConfigError(
+                            format!("{} tidak punya bobot untuk edge {} -> {}",
+                                    section, graph.node_names[i], graph.node_names[j])
+                        ));
+                    }
+                    None => row.push(0),
+                }
+            }
+            matrix.push(row);
+        }
+        Ok(matrix)
+    }
+
+    /// Section name if the line is a block header such as `MATRIX:`
+    fn section_header(line: &str) -> Result<Option<&str>> {
+        let Some(name) = line.strip_suffix(':') else {
+            return Ok(None);
+        };
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_uppercase() || c == '_') {
+            return Ok(None);
+        }
+
+        if SECTIONS.contains(&name) || name.strip_prefix("MATRIX_").is_some_and(|rest| !rest.is_empty()) {
+            Ok(Some(name))
+        } else {
+            Err(TSPError::ConfigError(format!("Section tidak dikenal: '{}'", line)))
+        }
+    }
+
+    fn is_setting(line: &str) -> bool {
+        SETTINGS.iter().any(|setting| line.starts_with(setting))
+    }
+
+    /// Rows of one block section (empty when the section is absent)
+    fn section_lines<'a>(content: &'a str, section: &str) -> Result<Vec<&'a str>> {
+        let mut rows = Vec::new();
+        let mut current = None;
+
+        for line in content.lines().map(|line| line.trim()) {
+            if line.is_empty() || line.starts_with('#') || Self::is_setting(line) {
+                continue;
+            }
+            match Self::section_header(line)? {
+                Some(name) => current = Some(name),
+                None if current == Some(section) => rows.push(line),
+                None => {}
+            }
+        }
+
+        Ok(rows)
+    }
+
+    /// `Kota: nilai nilai ...` row of a per-city section
+    fn parse_city_row<'a>(line: &'a str, graph: &Graph) -> Result<(usize, Vec<&'a str>)> {
+        let (city, values) = line.split_once(':').ok_or_else(|| TSPError::ConfigError(
+            format!("Format baris salah (harus 'kota: nilai ...'): '{}'", line)
+        ))?;
+        let node = graph.find_node(city).map_err(|e| TSPError::ConfigError(e.to_string()))?;
+
+        Ok((node, values.split_whitespace().collect()))
+    }
+
+    fn parse_number(value: &str) -> Result<i32> {
+        value.parse::<i32>()
+            .map_err(|_| TSPError::ConfigError(format!("Gagal parse angka: '{}'", value)))
+    }
+
+    /// TIME_WINDOWS section: `kota: paling_awal paling_akhir [durasi_layanan]`, `-` for no window
+    pub fn parse_time_windows(content: &str, graph: &Graph) -> Result<Vec<CityTiming>> {
+        let mut timings = vec![CityTiming::default(); graph.size];
+
+        for line in Self::section_lines(content, "TIME_WINDOWS")? {
+            let (node, values) = Self::parse_city_row(line, graph)?;
+            if values.len() < 2 || values.len() > 3 {
+                return Err(TSPError::ConfigError(
+                    format!("Jendela waktu harus berisi 2 atau 3 nilai: '{}'", line)
+                ));
+            }
+
+            timings[node].window = match (values[0], values[1]) {
+                ("-", "-") => None,
+                (earliest, latest) => Some(TimeWindow {
+                    earliest: Self::parse_number(earliest)?,
+                    latest: Self::parse_number(latest)?,
+                }),
+            };
+            if let Some(service) = values.get(2) {
+                timings[node].service_time = Self::parse_number(service)?;
+            }
+        }
+
+        Ok(timings)
+    }
+
+    /// PICKUP_DELIVERY section (`kota_pickup -> kota_delivery: muatan`) plus the CAPACITY setting,
+    /// None when the file has no requests
+    pub fn parse_pickup_delivery(content: &str, graph: &Graph) -> Result<Option<(Vec<DeliveryRequest>, i32)>> {
+        let mut requests = Vec::new();
+        for line in Self::section_lines(content, "PICKUP_DELIVERY")? {
+            let parsed = line.split_once("->").and_then(|(pickup, rest)| {
+                rest.split_once(':').map(|(delivery, load)| (pickup, delivery, load.trim()))
+            });
+            let (pickup, delivery, load) = parsed.ok_or_else(|| TSPError::ConfigError(
+                format!("Format permintaan salah (harus 'pickup -> delivery: muatan'): '{}'", line)
+            ))?;
+
+            requests.push(DeliveryRequest {
+                pickup: graph.find_node(pickup).map_err(|e| TSPError::ConfigError(e.to_string()))?,
+                delivery: graph.find_node(delivery).map_err(|e| TSPError::ConfigError(e.to_string()))?,
+                load: Self::parse_number(load)?,
+            });
+        }
+
+        if requests.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some((requests, Self::parse_capacity(content, "PICKUP_DELIVERY")?)))
+    }
+
+    /// DEMANDS section (`kota: permintaan`, unlisted cities demand 0) plus the CAPACITY setting,
+    /// None when the file has no demands
+    pub fn parse_demands(content: &str, graph: &Graph) -> Result<Option<(Vec<i32>, i32)>> {
+        let rows = Self::section_lines(content, "DEMANDS")?;
+        if rows.is_empty() {
+            return Ok(None);
+        }
+
+        let mut demands = vec![0; graph.size];
+        for line in rows {
+            let (node, values) = Self::parse_city_row(line, graph)?;
+            let [demand] = values[..] else {
+                return Err(TSPError::ConfigError(
+                    format!("Permintaan harus berisi 1 nilai: '{}'", line)
+                ));
+            };
+            demands[node] = Self::parse_number(demand)?;
+        }
+
+        Ok(Some((demands, Self::parse_capacity(content, "DEMANDS")?)))
+    }
+
+    /// PRIZES section: `kota: hadiah [penalti]` (unlisted cities get 0 for both)
+    pub fn parse_prizes(content: &str, graph: &Graph) -> Result<Vec<CityReward>> {
+        let mut rewards = vec![CityReward::default(); graph.size];
+
+        for line in Self::section_lines(content, "PRIZES")? {
+            let (node, values) = Self::parse_city_row(line, graph)?;
+            if values.is_empty() || values.len() > 2 {
+                return Err(TSPError::ConfigError(
+                    format!("Hadiah harus berisi 1 atau 2 nilai: '{}'", line)
+                ));
+            }
+
+            rewards[node].prize = Self::parse_number(values[0])?;
+            if let Some(penalty) = values.get(1) {
+                rewards[node].penalty = Self::parse_number(penalty)?;
+            }
+        }
+
+        Ok(rewards)
+    }
+
+    /// CLUSTERS section: `nama_cluster: kota, kota, ...`
+    pub fn parse_clusters(content: &str, graph: &Graph) -> Result<Vec<CityCluster>> {
+        let mut clusters = Vec::new();
+
+        for line in Self::section_lines(content, "CLUSTERS")? {
+            let (name, cities) = line.split_once(':').ok_or_else(|| TSPError::ConfigError(
+                format!("Format cluster salah (harus 'nama: kota, kota, ...'): '{}'", line)
+            ))?;
+            let cities = cities
+                .split(',')
+                .map(|city| graph.find_node(city).map_err(|e| TSPError::ConfigError(e.to_string())))
+                .collect::<Result<Vec<usize>>>()?;
+
+            clusters.push(CityCluster { name: name.trim().to_string(), cities });
+        }
+
+        Ok(clusters)
+    }
+
+    /// SCHEDULE section (`waktu_mulai: nama_matriks`, `jarak` is the MATRIX section)
+    pub fn parse_schedule(content: &str, graph: &Graph) -> Result<Vec<TimeSlot>> {
+        let mut slots = Vec::new();
+
+        for line in Self::section_lines(content, "SCHEDULE")? {
+            let (start, name) = line.split_once(':').ok_or_else(|| TSPError::ConfigError(
+                format!("Format jadwal salah (harus 'waktu_mulai: nama_matriks'): '{}'", line)
+            ))?;
+            let start = Self::parse_number(start.trim())?;
+            let criterion = graph.find_criterion(name).map_err(|e| TSPError::ConfigError(e.to_string()))?;
+
+            slots.push(TimeSlot { start, criterion });
+        }
+
+        if slots.is_empty() {
+            return Err(TSPError::ConfigError("Tidak ditemukan section SCHEDULE".to_string()));
+        }
+        Ok(slots)
+    }
+
+    /// DISTRIBUTIONS section: `kota_asal -> kota_tujuan: jenis parameter...`
+    pub fn parse_distributions(content: &str, graph: &Graph) -> Result<Vec<EdgeDistribution>> {
+        let mut distributions = Vec::new();
+
+        for line in Self::section_lines(content, "DISTRIBUTIONS")? {
+            let parsed = line.split_once("->").and_then(|(from, rest)| {
+                rest.split_once(':').map(|(to, spec)| (from, to, spec))
+            });
+            let (from, to, spec) = parsed.ok_or_else(|| TSPError::ConfigError(
+                format!("Format distribusi salah (harus 'kota_asal -> kota_tujuan: jenis parameter...'): '{}'", line)
+            ))?;
+            let from = graph.find_node(from).map_err(|e| TSPError::ConfigError(e.to_string()))?;
+            let to = graph.find_node(to).map_err(|e| TSPError::ConfigError(e.to_string()))?;
+
+            let mut tokens = spec.split_whitespace();
+            let kind = tokens.next().unwrap_or("");
+            let values = tokens
+                .map(|value| value.parse::<f64>().map_err(|_| TSPError::ConfigError(
+                    format!("Gagal parse angka: '{}'", value)
+                )))
+                .collect::<Result<Vec<f64>>>()?;
+
+            let distribution = match (kind, values.as_slice()) {
+                ("normal", &[mean, std_dev]) => CostDistribution::Normal { mean, std_dev },
+                ("seragam", &[low, high]) => CostDistribution::Uniform { low, high },
+                ("empiris", values) if !values.is_empty() => CostDistribution::Empirical(values.to_vec()),
+                _ => return Err(TSPError::ConfigError(
+                    format!("Distribusi tidak dikenal: '{}' (gunakan normal rata2 simpangan, seragam bawah atas, atau empiris nilai...)", spec.trim())
+                )),
+            };
+
+            distributions.push(EdgeDistribution { from, to, distribution });
+        }
+
+        Ok(distributions)
+    }
+
+    /// Uncertain `low..high` entries of the MATRIX section (the graph keeps their midpoint)
+    pub fn parse_cost_intervals(content: &str) -> Result<Vec<EdgeInterval>> {
+        let mut intervals = Vec::new();
+
+        for (from, line) in Self::section_lines(content, "MATRIX")?.into_iter().enumerate() {
+            for (to, interval) in Self::parse_matrix_row(line)?.into_iter().enumerate() {
+                if let Some(interval) = interval.filter(|interval| interval.low < interval.high) {
+                    intervals.push(EdgeInterval { from, to, interval });
+                }
+            }
         }
+
+        Ok(intervals)
     }
 
-    fn parse_matrix_row(line: &str) -> Result<Vec<i32>> {
+    /// CAPACITY setting, required by `section`
+    fn parse_capacity(content: &str, section: &str) -> Result<i32> {
+        let capacity = content
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("CAPACITY:"))
+            .ok_or_else(|| TSPError::ConfigError(
+                format!("Section {} memerlukan CAPACITY", section)
+            ))?;
+
+        Self::parse_number(capacity.trim())
+    }
+
+    /// Weights of one row, `-` for a missing edge, `low..high` for an uncertain one
+    fn parse_matrix_row(line: &str) -> Result<Vec<Option<CostInterval>>> {
+        let parse = |s: &str| s.parse::<i32>().map_err(|_| TSPError::ConfigError(
+            format!("Gagal parse angka: '{}'", s)
+        ));
+
         line.split_whitespace()
             .map(|s| {
-                s.parse::<i32>()
-                    .map_err(|_| TSPError::ConfigError(
-                        format!("Gagal parse angka: '{}'", s)
-                    ))
+                if s == "-" {
+                    return Ok(None);
+                }
+                let (low, high) = match s.split_once("..") {
+                    Some((low, high)) => (parse(low)?, parse(high)?),
+                    None => (parse(s)?, parse(s)?),
+                };
+                if low > high {
+                    return Err(TSPError::ConfigError(
+                        format!("Interval biaya '{}' tidak valid: batas bawah melebihi batas atas", s)
+                    ));
+                }
+                Ok(Some(CostInterval { low, high }))
             })
             .collect()
     }
@@ -158,6 +608,11 @@ MATRIX:
             errors.push(format!("Graf tidak valid untuk TSP: {}", e));
         }
 
+        // Missing edges are allowed, but may leave no closed tour at all
+        for reason in FeasibilityReport::analyze(&graph, &RouteMode::default()).reasons {
+            warnings.push(format!("Tidak ada tour tertutup: {}", reason));
+        }
+
         if graph.size > 10 {
             warnings.push(format!(
                 "Graf berukuran {} mungkin membutuhkan waktu komputasi yang lama", 
@@ -165,7 +620,7 @@ MATRIX:
             ));
         }
 
-        if stats.max_edge_weight > stats.min_edge_weight * 10 {
+        if stats.min_edge_weight > 0 && stats.max_edge_weight > stats.min_edge_weight * 10 {
             warnings.push("Bobot edge sangat tidak seimbang, ini mungkin mempengaruhi performa".to_string());
         }
 
@@ -244,6 +699,117 @@ MATRIX:
         assert_eq!(graph.get_distance(1, 2), 4);
     }
 
+    #[test]
+    fn test_parse_missing_edges() {
+        let content = r#"
+MATRIX:
+0 -  -2
+0 0  4
+5 6  -
+"#;
+
+        let graph = ConfigReader::parse_content(content).unwrap();
+        assert_eq!(graph.edge_weight(0, 1), None);
+        assert_eq!(graph.edge_weight(0, 2), Some(-2));
+        assert_eq!(graph.edge_weight(1, 0), Some(0));
+        assert_eq!(graph.get_stats().edges, 5);
+        assert!(graph.calculate_path_cost(&[0, 1]).is_err());
+    }
+
+    #[test]
+    fn test_parse_cost_intervals() {
+        let content = r#"
+MATRIX:
+0     8..12 5
+4     0     1..3
+-2..6 7     0
+"#;
+
+        let graph = ConfigReader::parse_content(content).unwrap();
+        assert_eq!(graph.get_distance(0, 1), 10);
+        assert_eq!(graph.get_distance(2, 0), 2);
+
+        let intervals = ConfigReader::parse_cost_intervals(content).unwrap();
+        assert_eq!(intervals.len(), 3);
+        assert_eq!(intervals[0], EdgeInterval { from: 0, to: 1, interval: CostInterval { low: 8, high: 12 } });
+        assert_eq!(intervals[2], EdgeInterval { from: 2, to: 0, interval: CostInterval { low: -2, high: 6 } });
+
+        assert!(ConfigReader::parse_content("MATRIX:\n0 5..3\n1 0\n").is_err());
+        assert!(ConfigReader::parse_content("MATRIX:\n0 1\n1 0\nMATRIX_WAKTU:\n0 1..2\n1 0\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_weight_matrices() {
+        let content = r#"
+CITIES: A, B, C
+MATRIX:
+0 1 -
+3 0 4
+5 6 0
+
+MATRIX_WAKTU:
+0 2 -
+7 0 1
+1 1 0
+"#;
+
+        let graph = ConfigReader::parse_content(content).unwrap();
+        assert_eq!(graph.criteria(), vec!["jarak", "waktu"]);
+        assert_eq!(graph.criterion_weight(1, 1, 0), 7);
+
+        let missing = content.replace("7 0 1", "- 0 1");
+        let message = ConfigReader::parse_content(&missing).unwrap_err().to_string();
+        assert!(message.contains("MATRIX_WAKTU tidak punya bobot untuk edge B -> A"), "{}", message);
+    }
+
+    #[test]
+    fn test_parse_schedule() {
+        let content = r#"
+MATRIX:
+0 1
+1 0
+MATRIX_MACET:
+0 5
+5 0
+SCHEDULE:
+0: jarak
+420: macet
+"#;
+
+        let graph = ConfigReader::parse_content(content).unwrap();
+        let slots = ConfigReader::parse_schedule(content, &graph).unwrap();
+        assert_eq!(slots, vec![TimeSlot { start: 0, criterion: 0 }, TimeSlot { start: 420, criterion: 1 }]);
+
+        let unknown = content.replace("420: macet", "420: malam");
+        assert!(ConfigReader::parse_schedule(&unknown, &graph).is_err());
+    }
+
+    #[test]
+    fn test_parse_distributions() {
+        let content = r#"
+CITIES: A, B, C
+MATRIX:
+0 10 15
+5 0 9
+6 13 0
+
+DISTRIBUTIONS:
+A -> B: normal 10 2.5
+B -> C: seragam 6 12
+C -> A: empiris 4 6 20
+"#;
+
+        let graph = ConfigReader::parse_content(content).unwrap();
+        let distributions = ConfigReader::parse_distributions(content, &graph).unwrap();
+        assert_eq!(distributions.len(), 3);
+        assert_eq!(distributions[0].distribution, CostDistribution::Normal { mean: 10.0, std_dev: 2.5 });
+        assert_eq!((distributions[1].from, distributions[1].to), (1, 2));
+        assert_eq!(distributions[2].distribution.mean(), 10.0);
+
+        let invalid = content.replace("seragam 6 12", "seragam 6");
+        assert!(ConfigReader::parse_distributions(&invalid, &graph).is_err());
+    }
+
     #[test]
     fn test_parse_with_cities() {
         let content = r#"
@@ -258,6 +824,167 @@ MATRIX:
         assert_eq!(graph.node_names, vec!["A", "B", "C"]);
     }
 
+    #[test]
+    fn test_parse_time_windows() {
+        let content = r#"
+CITIES: A, B, C
+MATRIX:
+0 1 2
+3 0 4
+5 6 0
+
+TIME_WINDOWS:
+B: 10 40 5
+C: - - 3
+"#;
+
+        let graph = ConfigReader::parse_content(content).unwrap();
+        assert_eq!(graph.size, 3);
+
+        let timings = ConfigReader::parse_time_windows(content, &graph).unwrap();
+        assert_eq!(timings[0], CityTiming::default());
+        assert_eq!(timings[1].window, Some(TimeWindow { earliest: 10, latest: 40 }));
+        assert_eq!(timings[1].service_time, 5);
+        assert_eq!(timings[2].window, None);
+        assert_eq!(timings[2].service_time, 3);
+    }
+
+    #[test]
+    fn test_parse_precedence() {
+        let content = r#"
+CITIES: A, B, C
+MATRIX:
+0 1 2
+1 0 3
+2 3 0
+
+PRECEDENCE:
+C -> B
+1 -> 3
+"#;
+
+        let graph = ConfigReader::parse_content(content).unwrap();
+        assert_eq!(graph.precedences, vec![(2, 1), (0, 2)]);
+        assert!(ConfigReader::parse_content(&content.replace("C -> B", "C B")).is_err());
+    }
+
+    #[test]
+    fn test_parse_edge_constraints() {
+        let content = r#"
+CITIES: A, B, C
+MATRIX:
+0 1 2
+1 0 3
+2 3 0
+
+EDGES:
+A -> C: wajib
+B -> A: terlarang
+"#;
+
+        let graph = ConfigReader::parse_content(content).unwrap();
+        assert_eq!(graph.edge_constraints.required, vec![(0, 2)]);
+        assert!(graph.edge_constraints.forbidden.contains(&(1, 0)));
+        assert!(ConfigReader::parse_content(&content.replace("terlarang", "mungkin")).is_err());
+    }
+
+    #[test]
+    fn test_parse_pickup_delivery() {
+        let content = r#"
+CITIES: A, B, C
+CAPACITY: 8
+MATRIX:
+0 1 2
+1 0 3
+2 3 0
+
+PICKUP_DELIVERY:
+B -> C: 5
+"#;
+
+        let graph = ConfigReader::parse_content(content).unwrap();
+        let (requests, capacity) = ConfigReader::parse_pickup_delivery(content, &graph).unwrap().unwrap();
+        assert_eq!(requests, vec![DeliveryRequest { pickup: 1, delivery: 2, load: 5 }]);
+        assert_eq!(capacity, 8);
+
+        let missing_capacity = content.replace("CAPACITY: 8", "");
+        assert!(ConfigReader::parse_pickup_delivery(&missing_capacity, &graph).is_err());
+    }
+
+    #[test]
+    fn test_parse_demands() {
+        let content = r#"
+CITIES: A, B, C
+MATRIX:
+0 1 2
+1 0 3
+2 3 0
+
+CAPACITY: 10
+DEMANDS:
+B: 4
+C: 6
+"#;
+
+        let graph = ConfigReader::parse_content(content).unwrap();
+        let (demands, capacity) = ConfigReader::parse_demands(content, &graph).unwrap().unwrap();
+        assert_eq!(demands, vec![0, 4, 6]);
+        assert_eq!(capacity, 10);
+        assert!(ConfigReader::parse_demands(&content.replace("C: 6", "C: 6 1"), &graph).is_err());
+    }
+
+    #[test]
+    fn test_parse_prizes() {
+        let content = r#"
+CITIES: A, B, C
+MATRIX:
+0 1 2
+1 0 3
+2 3 0
+
+PRIZES:
+B: 7 2
+C: 4
+"#;
+
+        let graph = ConfigReader::parse_content(content).unwrap();
+        let rewards = ConfigReader::parse_prizes(content, &graph).unwrap();
+        assert_eq!(rewards[1], CityReward { prize: 7, penalty: 2 });
+        assert_eq!(rewards[2], CityReward { prize: 4, penalty: 0 });
+        assert_eq!(rewards[0], CityReward::default());
+    }
+
+    #[test]
+    fn test_parse_clusters() {
+        let content = r#"
+CITIES: A, B, C
+MATRIX:
+0 1 2
+1 0 3
+2 3 0
+
+CLUSTERS:
+Cabang: B, C
+"#;
+
+        let graph = ConfigReader::parse_content(content).unwrap();
+        let clusters = ConfigReader::parse_clusters(content, &graph).unwrap();
+        assert_eq!(clusters, vec![CityCluster { name: "Cabang".to_string(), cities: vec![1, 2] }]);
+        assert!(ConfigReader::parse_clusters(&content.replace("B, C", "B, X"), &graph).is_err());
+    }
+
+    #[test]
+    fn test_unknown_section() {
+        let content = r#"
+MATRIX:
+0 1
+1 0
+MATIRX:
+"#;
+
+        assert!(ConfigReader::parse_content(content).is_err());
+    }
+
     #[test]
     fn test_invalid_matrix() {
         let content = r#"
@@ -0,0 +1,368 @@
+/// Libraries
+use crate::heuristics::local_search;
+use crate::tsp_solver::SolverStats;
+use crate::{Graph, Result, TSPError};
+use std::time::Instant;
+
+/// Upper bound on inter-route improvement passes
+const MAX_PASSES: usize = 1_000;
+
+/// One vehicle: a closed route from the depot
+#[derive(Debug, Clone, PartialEq)]
+pub struct VehicleRoute {
+    pub path: Vec<usize>,
+    pub load: i32,
+    pub cost: i32,
+}
+
+/// Result
+#[derive(Debug)]
+pub struct CVRPSolution {
+    pub routes: Vec<VehicleRoute>,
+    pub total_cost: i32,
+    pub capacity: i32,
+    pub depot: usize,
+    pub stats: SolverStats,
+    pub is_valid: bool,
+    pub issues: Vec<String>,
+}
+
+/// Capacitated vehicle routing: an unlimited fleet of identical vehicles serves every demand
+pub struct CVRPSolver {
+    graph: Graph,
+    demands: Vec<i32>,
+    capacity: i32,
+    depot: usize,
+}
+
+impl CVRPSolver {
+    pub fn new(graph: Graph, demands: Vec<i32>, capacity: i32) -> Result<Self> {
+        graph.validate_for_tsp()?;
+
+        if demands.len() != graph.size {
+            return Err(TSPError::InvalidGraph(
+                format!("Jumlah permintaan ({}) tidak sesuai dengan ukuran graf ({})",
+                        demands.len(), graph.size)
+            ));
+        }
+        if capacity <= 0 {
+            return Err(TSPError::InvalidGraph(
+                format!("Kapasitas kendaraan harus > 0 (nilai: {})", capacity)
+            ));
+        }
+
+        Ok(CVRPSolver {
+            graph,
+            demands,
+            capacity,
+            depot: 0,
+        })
+    }
+
+    pub fn with_depot(mut self, depot: usize) -> Self {
+        self.depot = depot;
+        self
+    }
+
+    /// Clarke-Wright savings, then local search inside and between routes
+    pub fn solve(&self) -> Result<CVRPSolution> {
+        self.validate()?;
+
+        let mut stats = SolverStats {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        };
+
+        let mut routes = self.savings_routes(&mut stats);
+        self.improve_between_routes(&mut routes, &mut stats);
+
+        let routes = routes
+            .into_iter()
+            .filter(|customers| !customers.is_empty())
+            .map(|customers| {
+                let mut path = vec![self.depot];
+                path.extend(customers);
+                path.push(self.depot);
+
+                let movable = 1..path.len() - 1;
+                let path = local_search(path.clone(), movable, |r| self.route_cost(r), &mut stats)
+                    .map_or(path, |(_, improved)| improved);
+                let cost = self.route_cost(&path).unwrap_or(0);
+                VehicleRoute { load: self.load(&path), path, cost }
+            })
+            .collect();
+
+        Ok(self.build_solution(routes, stats))
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.depot >= self.graph.size {
+            return Err(TSPError::SolverError(
+                format!("Depot {} di luar jangkauan graf", self.depot)
+            ));
+        }
+
+        for (city, &demand) in self.demands.iter().enumerate() {
+            if demand < 0 {
+                return Err(TSPError::InvalidGraph(
+                    format!("Permintaan {} tidak boleh negatif", self.graph.node_names[city])
+                ));
+            }
+            if city != self.depot && demand > self.capacity {
+                return Err(TSPError::SolverError(
+                    format!("Permintaan {} ({}) melebihi kapasitas kendaraan ({})",
+                            self.graph.node_names[city], demand, self.capacity)
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn customers(&self) -> Vec<usize> {
+        (0..self.graph.size).filter(|&v| v != self.depot).collect()
+    }
+
+    /// Merge routes in order of savings s(i, j) = c(i, depot) + c(depot, j) - c(i, j)
+    /// (the route ending at i is followed by the route starting at j)
+    fn savings_routes(&self, stats: &mut SolverStats) -> Vec<Vec<usize>> {
+        let d = self.depot;
+        let mut routes: Vec<Vec<usize>> = self.customers().into_iter().map(|c| vec![c]).collect();
+        let mut route_of: Vec<usize> = vec![usize::MAX; self.graph.size];
+        for (r, route) in routes.iter().enumerate() {
+            route_of[route[0]] = r;
+        }
+
+        let mut savings = Vec::new();
+        for i in self.customers() {
+            for j in self.customers() {
+                if self.graph.can_travel(i, j) && self.graph.can_travel(i, d) && self.graph.can_travel(d, j) {
+                    let saving = self.graph.get_distance(i, d) + self.graph.get_distance(d, j)
+                        - self.graph.get_distance(i, j);
+                    if saving > 0 {
+                        savings.push((saving, i, j));
+                    }
+                }
+            }
+        }
+        savings.sort_by_key(|&(saving, i, j)| (std::cmp::Reverse(saving), i, j));
+
+        for (_, i, j) in savings {
+            let (a, b) = (route_of[i], route_of[j]);
+            stats.states_computed += 1;
+            if a == b || routes[a].last() != Some(&i) || routes[b].first() != Some(&j) {
+                continue;
+            }
+            if self.customer_load(&routes[a]) + self.customer_load(&routes[b]) > self.capacity {
+                continue;
+            }
+
+            let merged = std::mem::take(&mut routes[b]);
+            for &city in &merged {
+                route_of[city] = a;
+            }
+            routes[a].extend(merged);
+        }
+
+        routes.retain(|route| !route.is_empty());
+        routes
+    }
+
+    /// Relocate customers to another route whenever it fits and lowers the total cost
+    fn improve_between_routes(&self, routes: &mut Vec<Vec<usize>>, stats: &mut SolverStats) {
+        for _ in 0..MAX_PASSES {
+            let mut improved = false;
+
+            'search: for from in 0..routes.len() {
+                for position in 0..routes[from].len() {
+                    let city = routes[from][position];
+                    let mut shorter = routes[from].clone();
+                    shorter.remove(position);
+                    let Some(removal_gain) = self.customer_cost(&routes[from])
+                        .zip(self.customer_cost(&shorter))
+                        .map(|(before, after)| before - after)
+                    else {
+                        continue;
+                    };
+
+                    for to in (0..routes.len()).filter(|&to| to != from) {
+                        if self.customer_load(&routes[to]) + self.demands[city] > self.capacity {
+                            continue;
+                        }
+
+                        for insert_at in 0..=routes[to].len() {
+                            let mut longer = routes[to].clone();
+                            longer.insert(insert_at, city);
+                            stats.states_computed += 1;
+
+                            let Some(insertion_cost) = self.customer_cost(&longer)
+                                .zip(self.customer_cost(&routes[to]))
+                                .map(|(after, before)| after - before)
+                            else {
+                                continue;
+                            };
+
+                            if insertion_cost < removal_gain {
+                                routes[from] = shorter;
+                                routes[to] = longer;
+                                improved = true;
+                                break 'search;
+                            }
+                        }
+                    }
+                }
+            }
+
+            routes.retain(|route| !route.is_empty());
+            if !improved {
+                break;
+            }
+        }
+    }
+
+    fn customer_load(&self, customers: &[usize]) -> i32 {
+        customers.iter().map(|&c| self.demands[c]).sum()
+    }
+
+    /// Cost of a depot -> customers -> depot route (0 for an empty one)
+    fn customer_cost(&self, customers: &[usize]) -> Option<i32> {
+        if customers.is_empty() {
+            return Some(0);
+        }
+        let mut path = vec![self.depot];
+        path.extend_from_slice(customers);
+        path.push(self.depot);
+        self.route_cost(&path)
+    }
+
+    fn route_cost(&self, route: &[usize]) -> Option<i32> {
+        route.windows(2).try_fold(0, |total, w| {
+            self.graph.can_travel(w[0], w[1]).then(|| total + self.graph.get_distance(w[0], w[1]))
+        })
+    }
+
+    fn load(&self, path: &[usize]) -> i32 {
+        path.iter().filter(|&&c| c != self.depot).map(|&c| self.demands[c]).sum()
+    }
+
+    /// Every route is re-checked with `calculate_path_cost` and against the capacity
+    fn build_solution(&self, routes: Vec<VehicleRoute>, mut stats: SolverStats) -> CVRPSolution {
+        let mut issues = Vec::new();
+        let mut visits = vec![0usize; self.graph.size];
+
+        for (index, route) in routes.iter().enumerate() {
+            let vehicle = index + 1;
+            match self.graph.calculate_path_cost(&route.path) {
+                Ok(cost) if cost != route.cost => issues.push(
+                    format!("Kendaraan {}: biaya {} berbeda dengan biaya sebenarnya {}", vehicle, route.cost, cost)
+                ),
+                Ok(_) => {}
+                Err(e) => issues.push(format!("Kendaraan {}: {}", vehicle, e)),
+            }
+
+            if route.load > self.capacity {
+                issues.push(format!("Kendaraan {}: muatan {} melebihi kapasitas {}", vehicle, route.load, self.capacity));
+            }
+            if route.path.first() != Some(&self.depot) || route.path.last() != Some(&self.depot) {
+                issues.push(format!("Kendaraan {}: rute tidak berawal dan berakhir di depot", vehicle));
+            }
+            for &city in route.path.iter().filter(|&&city| city != self.depot) {
+                visits[city] += 1;
+            }
+        }
+
+        for city in self.customers() {
+            if visits[city] != 1 {
+                issues.push(format!("{} dilayani {} kali", self.graph.node_names[city], visits[city]));
+            }
+        }
+
+        stats.solve_duration = stats.start_time.map(|t| t.elapsed());
+
+        CVRPSolution {
+            total_cost: routes.iter().map(|route| route.cost).sum(),
+            routes,
+            capacity: self.capacity,
+            depot: self.depot,
+            stats,
+            is_valid: issues.is_empty(),
+            issues,
+        }
+    }
+}
+
+impl std::fmt::Display for CVRPSolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "=== CVRP SOLUTION ===")?;
+        writeln!(f, "Vehicles: {}", self.routes.len())?;
+        writeln!(f, "Capacity: {}", self.capacity)?;
+        for (i, route) in self.routes.iter().enumerate() {
+            writeln!(f, "Vehicle {}: {} (load {}, cost {})", i + 1,
+                    route.path.iter()
+                        .map(|&v| (v + 1).to_string())
+                        .collect::<Vec<_>>()
+                        .join(" -> "),
+                    route.load, route.cost)?;
+        }
+        writeln!(f, "Total Cost: {}", self.total_cost)?;
+        writeln!(f, "Valid: {}", if self.is_valid { "Yes" } else { "No" })?;
+        writeln!(f, "{}", self.stats)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two clusters (1, 2) and (3, 4) on opposite sides of the depot
+    fn clustered_graph() -> Graph {
+        let matrix = vec![
+            vec![ 0, 10, 10, 10, 10],
+            vec![10,  0,  2, 18, 18],
+            vec![10,  2,  0, 18, 18],
+            vec![10, 18, 18,  0,  2],
+            vec![10, 18, 18,  2,  0],
+        ];
+        Graph::new(matrix).unwrap()
+    }
+
+    #[test]
+    fn test_cvrp_one_vehicle_per_cluster() -> Result<()> {
+        let solution = CVRPSolver::new(clustered_graph(), vec![0, 3, 3, 3, 3], 6)?.solve()?;
+
+        assert!(solution.is_valid, "{:?}", solution.issues);
+        assert_eq!(solution.routes.len(), 2);
+        assert_eq!(solution.total_cost, 44);
+        assert!(solution.routes.iter().all(|route| route.load == 6 && route.cost == 22));
+
+        // Enough capacity for everything: a single route through both clusters
+        let solution = CVRPSolver::new(clustered_graph(), vec![0, 3, 3, 3, 3], 12)?.solve()?;
+        assert_eq!(solution.routes.len(), 1);
+        assert_eq!(solution.total_cost, 42);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cvrp_demand_above_capacity() -> Result<()> {
+        let solver = CVRPSolver::new(clustered_graph(), vec![0, 3, 7, 3, 3], 6)?;
+        let message = solver.solve().unwrap_err().to_string();
+        assert!(message.contains("Kota_3"), "{}", message);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cvrp_forbidden_edges() -> Result<()> {
+        let mut graph = clustered_graph();
+        graph.forbid_edge(1, 2)?;
+        graph.forbid_edge(2, 1)?;
+        let solution = CVRPSolver::new(graph, vec![0, 3, 3, 3, 3], 6)?.solve()?;
+
+        assert!(solution.is_valid, "{:?}", solution.issues);
+        assert!(solution.routes.iter().all(|route| !route.path.windows(2).any(|w| w == [1, 2] || w == [2, 1])));
+        assert!(solution.total_cost > 44);
+
+        Ok(())
+    }
+}
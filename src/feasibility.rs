@@ -0,0 +1,403 @@
+/// Libraries
+use crate::tsp_solver::RouteMode;
+use crate::{Graph, Result, TSPError};
+
+/// One reason why no route through every city can exist
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InfeasibilityReason {
+    NoOutgoingEdge { city: String },
+    NoIncomingEdge { city: String },
+    /// Cities of `unreachable` cannot be reached from any city of `from`
+    Unreachable { unreachable: Vec<String>, from: Vec<String> },
+    /// A path would have to start at each of these cities (nothing enters them)
+    SeveralStarts { cities: Vec<String> },
+    /// A path would have to end at each of these cities (nothing leaves them)
+    SeveralEnds { cities: Vec<String> },
+    /// Forced edges (the only way out of or into a city) leave `city` towards each of `next`
+    ForcedSuccessors { city: String, next: Vec<String> },
+    /// Forced edges enter `city` from each of `previous`
+    ForcedPredecessors { city: String, previous: Vec<String> },
+    /// Forced edges close a cycle before every city is visited
+    ForcedSubtour { cycle: Vec<String> },
+    /// A required edge leaves the fixed end of a path
+    RequiredEdgeLeavesEnd { from: String, to: String },
+    /// A required edge enters the fixed start of a path
+    RequiredEdgeEntersStart { from: String, to: String },
+}
+
+impl std::fmt::Display for InfeasibilityReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InfeasibilityReason::NoOutgoingEdge { city } => write!(f,
+                "{} tidak punya edge keluar, rute tidak bisa meninggalkannya", city),
+            InfeasibilityReason::NoIncomingEdge { city } => write!(f,
+                "{} tidak punya edge masuk, rute tidak bisa mencapainya", city),
+            InfeasibilityReason::Unreachable { unreachable, from } => write!(f,
+                "{} tidak dapat dicapai dari {}", unreachable.join(", "), from.join(", ")),
+            InfeasibilityReason::SeveralStarts { cities } => write!(f,
+                "{} tidak punya edge masuk, padahal rute hanya punya satu kota awal", cities.join(", ")),
+            InfeasibilityReason::SeveralEnds { cities } => write!(f,
+                "{} tidak punya edge keluar, padahal rute hanya punya satu kota akhir", cities.join(", ")),
+            InfeasibilityReason::ForcedSuccessors { city, next } => write!(f,
+                "{} harus langsung dilanjutkan ke {} sekaligus", city, next.join(" dan ")),
+            InfeasibilityReason::ForcedPredecessors { city, previous } => write!(f,
+                "{} harus langsung didatangi dari {} sekaligus", city, previous.join(" dan ")),
+            InfeasibilityReason::ForcedSubtour { cycle } => write!(f,
+                "edge yang tidak punya alternatif membentuk putaran {} sebelum semua kota dikunjungi",
+                cycle.join(" -> ")),
+            InfeasibilityReason::RequiredEdgeLeavesEnd { from, to } => write!(f,
+                "edge wajib {} -> {} tidak bisa dipakai karena rute berakhir di {}", from, to, from),
+            InfeasibilityReason::RequiredEdgeEntersStart { from, to } => write!(f,
+                "edge wajib {} -> {} tidak bisa dipakai karena rute berawal di {}", from, to, to),
+        }
+    }
+}
+
+/// Cheap necessary conditions for a Hamiltonian route: degrees, strongly connected components
+/// and edges forced by a single choice. Passing them does not prove that a route exists.
+#[derive(Debug, Clone, Default)]
+pub struct FeasibilityReport {
+    pub reasons: Vec<InfeasibilityReason>,
+}
+
+impl FeasibilityReport {
+    pub fn analyze(graph: &Graph, mode: &RouteMode) -> Self {
+        let usable = |from: usize, to: usize| {
+            graph.has_edge(from, to) && !graph.edge_constraints.forbidden.contains(&(from, to))
+        };
+        let successors: Vec<Vec<usize>> = (0..graph.size)
+            .map(|v| (0..graph.size).filter(|&w| usable(v, w)).collect())
+            .collect();
+        let predecessors: Vec<Vec<usize>> = (0..graph.size)
+            .map(|v| (0..graph.size).filter(|&w| usable(w, v)).collect())
+            .collect();
+
+        let mut reasons = Vec::new();
+        if graph.size < 2 {
+            return FeasibilityReport { reasons };
+        }
+
+        if mode.is_closed() {
+            Self::check_tour(graph, &successors, &predecessors, &mut reasons);
+        } else {
+            Self::check_path(graph, mode, &successors, &predecessors, &mut reasons);
+        }
+
+        FeasibilityReport { reasons }
+    }
+
+    pub fn is_feasible(&self) -> bool {
+        self.reasons.is_empty()
+    }
+
+    /// Error naming every reason found
+    pub fn into_result(self) -> Result<()> {
+        if self.is_feasible() {
+            return Ok(());
+        }
+
+        Err(TSPError::SolverError(format!("Tidak ada rute yang mungkin: {}",
+            self.reasons.iter().map(|reason| reason.to_string()).collect::<Vec<_>>().join("; "))))
+    }
+
+    fn check_tour(graph: &Graph, successors: &[Vec<usize>], predecessors: &[Vec<usize>], reasons: &mut Vec<InfeasibilityReason>) {
+        let names = |nodes: &[usize]| nodes.iter().map(|&v| graph.node_names[v].clone()).collect::<Vec<_>>();
+
+        for v in 0..graph.size {
+            if successors[v].is_empty() {
+                reasons.push(InfeasibilityReason::NoOutgoingEdge { city: graph.node_names[v].clone() });
+            }
+            if predecessors[v].is_empty() {
+                reasons.push(InfeasibilityReason::NoIncomingEdge { city: graph.node_names[v].clone() });
+            }
+        }
+        if !reasons.is_empty() {
+            return;
+        }
+
+        // The first component in topological order is entered from nowhere else
+        let components = strongly_connected_components(successors);
+        if components.len() > 1 {
+            let rest: Vec<usize> = components[1..].iter().flatten().copied().collect();
+            reasons.push(InfeasibilityReason::Unreachable {
+                unreachable: names(&components[0]),
+                from: names(&rest),
+            });
+            return;
+        }
+
+        // A city with a single way out (or in) fixes that edge of the tour
+        let forced = |from: usize, to: usize| successors[from] == [to] || predecessors[to] == [from];
+        for city in 0..graph.size {
+            let next: Vec<usize> = successors[city].iter().copied().filter(|&w| forced(city, w)).collect();
+            if next.len() > 1 {
+                reasons.push(InfeasibilityReason::ForcedSuccessors {
+                    city: graph.node_names[city].clone(),
+                    next: names(&next),
+                });
+            }
+            let previous: Vec<usize> = predecessors[city].iter().copied().filter(|&v| forced(v, city)).collect();
+            if previous.len() > 1 {
+                reasons.push(InfeasibilityReason::ForcedPredecessors {
+                    city: graph.node_names[city].clone(),
+                    previous: names(&previous),
+                });
+            }
+        }
+        if !reasons.is_empty() {
+            return;
+        }
+
+        let forced_next: Vec<Option<usize>> = (0..graph.size)
+            .map(|v| successors[v].iter().copied().find(|&w| forced(v, w)))
+            .collect();
+
+        for start in 0..graph.size {
+            let mut cycle = vec![start];
+            let mut current = start;
+            while let Some(next) = forced_next[current] {
+                cycle.push(next);
+                if next == start || cycle.len() > graph.size {
+                    break;
+                }
+                current = next;
+            }
+            if cycle.len() <= graph.size && cycle.last() == Some(&start) && cycle.len() > 1 {
+                reasons.push(InfeasibilityReason::ForcedSubtour { cycle: names(&cycle) });
+                return;
+            }
+        }
+    }
+
+    fn check_path(
+        graph: &Graph,
+        mode: &RouteMode,
+        successors: &[Vec<usize>],
+        predecessors: &[Vec<usize>],
+        reasons: &mut Vec<InfeasibilityReason>,
+    ) {
+        let names = |nodes: &[usize]| nodes.iter().map(|&v| graph.node_names[v].clone()).collect::<Vec<_>>();
+
+        // Nothing leaves the end of a path and nothing enters its start
+        for &(from, to) in &graph.edge_constraints.required {
+            let (from_name, to_name) = (graph.node_names[from].clone(), graph.node_names[to].clone());
+            if mode.end() == Some(from) {
+                reasons.push(InfeasibilityReason::RequiredEdgeLeavesEnd { from: from_name, to: to_name });
+            } else if mode.start() == Some(to) {
+                reasons.push(InfeasibilityReason::RequiredEdgeEntersStart { from: from_name, to: to_name });
+            }
+        }
+        if !reasons.is_empty() {
+            return;
+        }
+
+        // Only the start may lack a way in, only the end may lack a way out
+        let sources: Vec<usize> = (0..graph.size).filter(|&v| predecessors[v].is_empty()).collect();
+        let sinks: Vec<usize> = (0..graph.size).filter(|&v| successors[v].is_empty()).collect();
+        match mode.start() {
+            Some(start) => reasons.extend(sources.iter().filter(|&&v| v != start)
+                .map(|&v| InfeasibilityReason::NoIncomingEdge { city: graph.node_names[v].clone() })),
+            None if sources.len() > 1 => reasons.push(InfeasibilityReason::SeveralStarts { cities: names(&sources) }),
+            None => {}
+        }
+        match mode.end() {
+            Some(end) => reasons.extend(sinks.iter().filter(|&&v| v != end)
+                .map(|&v| InfeasibilityReason::NoOutgoingEdge { city: graph.node_names[v].clone() })),
+            None if sinks.len() > 1 => reasons.push(InfeasibilityReason::SeveralEnds { cities: names(&sinks) }),
+            None => {}
+        }
+        if !reasons.is_empty() {
+            return;
+        }
+
+        // The path crosses the components in topological order, so consecutive ones must be linked
+        let components = strongly_connected_components(successors);
+        for pair in components.windows(2) {
+            let linked = pair[0].iter().any(|&v| pair[1].iter().any(|&w| successors[v].contains(&w)));
+            if !linked {
+                reasons.push(InfeasibilityReason::Unreachable {
+                    unreachable: names(&pair[1]),
+                    from: names(&pair[0]),
+                });
+                return;
+            }
+        }
+
+        if let Some(start) = mode.start().filter(|start| !components[0].contains(start)) {
+            reasons.push(InfeasibilityReason::Unreachable {
+                unreachable: names(&components[0]),
+                from: vec![graph.node_names[start].clone()],
+            });
+        }
+        if let Some(end) = mode.end().filter(|end| !components[components.len() - 1].contains(end)) {
+            reasons.push(InfeasibilityReason::Unreachable {
+                unreachable: vec![graph.node_names[end].clone()],
+                from: names(&components[components.len() - 1]),
+            });
+        }
+    }
+}
+
+impl std::fmt::Display for FeasibilityReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "=== FEASIBILITY REPORT ===")?;
+        if self.reasons.is_empty() {
+            writeln!(f, "Status: [OK] (no obstacle found)")?;
+        } else {
+            for reason in &self.reasons {
+                writeln!(f, "  [ERROR] {}", reason)?;
+            }
+            writeln!(f, "Status: [INFEASIBLE]")?;
+        }
+        Ok(())
+    }
+}
+
+/// Kosaraju: components in topological order of the condensation, each sorted
+fn strongly_connected_components(successors: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let size = successors.len();
+
+    fn finish_order(v: usize, successors: &[Vec<usize>], seen: &mut [bool], order: &mut Vec<usize>) {
+        seen[v] = true;
+        for &w in &successors[v] {
+            if !seen[w] {
+                finish_order(w, successors, seen, order);
+            }
+        }
+        order.push(v);
+    }
+
+    fn collect(v: usize, predecessors: &[Vec<usize>], seen: &mut [bool], component: &mut Vec<usize>) {
+        seen[v] = true;
+        component.push(v);
+        for &w in &predecessors[v] {
+            if !seen[w] {
+                collect(w, predecessors, seen, component);
+            }
+        }
+    }
+
+    let mut seen = vec![false; size];
+    let mut order = Vec::new();
+    for v in 0..size {
+        if !seen[v] {
+            finish_order(v, successors, &mut seen, &mut order);
+        }
+    }
+
+    let mut predecessors = vec![Vec::new(); size];
+    for (v, next) in successors.iter().enumerate() {
+        for &w in next {
+            predecessors[w].push(v);
+        }
+    }
+
+    let mut seen = vec![false; size];
+    let mut components = Vec::new();
+    for &v in order.iter().rev() {
+        if !seen[v] {
+            let mut component = Vec::new();
+            collect(v, &predecessors, &mut seen, &mut component);
+            component.sort_unstable();
+            components.push(component);
+        }
+    }
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 1 marks an edge, 0 a missing one
+    fn named(matrix: Vec<Vec<i32>>) -> Graph {
+        let names = ["A", "B", "C", "D"].iter().take(matrix.len()).map(|s| s.to_string()).collect();
+        let matrix = matrix.into_iter()
+            .map(|row| row.into_iter().map(|weight| (weight != 0).then_some(weight)).collect())
+            .collect();
+        let mut graph = Graph::from_optional_matrix(matrix).unwrap();
+        graph.set_node_names(names).unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_components_and_degrees() {
+        // {A, B} and {C, D} are two cycles joined only by B -> C
+        let graph = named(vec![
+            vec![0, 1, 0, 0],
+            vec![1, 0, 1, 0],
+            vec![0, 0, 0, 1],
+            vec![0, 0, 1, 0],
+        ]);
+        let report = FeasibilityReport::analyze(&graph, &RouteMode::default());
+        assert_eq!(report.reasons, vec![InfeasibilityReason::Unreachable {
+            unreachable: vec!["A".to_string(), "B".to_string()],
+            from: vec!["C".to_string(), "D".to_string()],
+        }]);
+
+        // An open path A -> B -> C -> D exists
+        assert!(FeasibilityReport::analyze(&graph, &RouteMode::OpenPath { start: None }).is_feasible());
+        let message = FeasibilityReport::analyze(&graph, &RouteMode::OpenPath { start: Some(2) })
+            .into_result().unwrap_err().to_string();
+        assert!(message.contains("A, B tidak dapat dicapai dari C"), "{}", message);
+
+        // D loses its only way out
+        let mut graph = graph;
+        graph.remove_edge(3, 2).unwrap();
+        let report = FeasibilityReport::analyze(&graph, &RouteMode::default());
+        assert!(report.reasons.contains(&InfeasibilityReason::NoOutgoingEdge { city: "D".to_string() }));
+    }
+
+    #[test]
+    fn test_forced_edges() {
+        // B and C can only go to A
+        let graph = named(vec![
+            vec![0, 1, 1, 1],
+            vec![1, 0, 0, 0],
+            vec![1, 0, 0, 0],
+            vec![1, 1, 1, 0],
+        ]);
+        let report = FeasibilityReport::analyze(&graph, &RouteMode::default());
+        assert_eq!(report.reasons, vec![InfeasibilityReason::ForcedPredecessors {
+            city: "A".to_string(),
+            previous: vec!["B".to_string(), "C".to_string()],
+        }]);
+
+        // A only leads to B and only B leads to A
+        let graph = named(vec![
+            vec![0, 1, 0, 0],
+            vec![1, 0, 1, 0],
+            vec![0, 0, 0, 1],
+            vec![0, 1, 1, 0],
+        ]);
+        let message = FeasibilityReport::analyze(&graph, &RouteMode::default())
+            .into_result().unwrap_err().to_string();
+        assert!(message.contains("A -> B -> A"), "{}", message);
+    }
+
+    #[test]
+    fn test_required_edge_at_path_endpoint() {
+        let mut graph = named(vec![
+            vec![0, 10, 15, 20],
+            vec![5,  0,  9, 10],
+            vec![6, 13,  0, 12],
+            vec![8,  8,  9,  0],
+        ]);
+        graph.require_edge(3, 0).unwrap();
+
+        let mode = RouteMode::PathBetween { start: 0, end: 3 };
+        let report = FeasibilityReport::analyze(&graph, &mode);
+        assert_eq!(report.reasons, vec![InfeasibilityReason::RequiredEdgeLeavesEnd {
+            from: "D".to_string(),
+            to: "A".to_string(),
+        }]);
+
+        let message = crate::TSPSolver::new(graph.clone()).unwrap().with_mode(mode).solve().unwrap_err().to_string();
+        assert!(message.contains("Tidak ada rute yang mungkin"), "{}", message);
+
+        // Entering the start is just as impossible, while a tour may use the arc
+        let report = FeasibilityReport::analyze(&graph, &RouteMode::PathBetween { start: 0, end: 2 });
+        assert!(report.reasons.iter().any(|reason| matches!(reason, InfeasibilityReason::RequiredEdgeEntersStart { .. })));
+        assert!(FeasibilityReport::analyze(&graph, &RouteMode::default()).is_feasible());
+    }
+}
@@ -1,17 +1,26 @@
 /// Libraries
+use crate::tsp_solver::{EdgeConstraints, RouteMode};
 use crate::{Result, TSPError};
 use colored::*;
 use prettytable::{Cell, Row, Table};
+use std::collections::HashSet;
 
 #[derive(Clone, Debug)]
 pub struct Graph {
     pub nodes: Vec<usize>,
-    pub adjacency_matrix: Vec<Vec<i32>>,
+    pub adjacency_matrix: Vec<Vec<i32>>, // weights may be zero or negative
+    pub missing_edges: HashSet<(usize, usize)>, // arcs absent from the graph (their weight is ignored)
+    pub weight_matrices: Vec<(String, Vec<Vec<i32>>)>, // further named criteria over the same arcs
     pub size: usize,
     pub node_names: Vec<String>,
+    pub precedences: Vec<(usize, usize)>, // (before, after): `before` must be visited first
+    pub edge_constraints: EdgeConstraints, // arcs every route must use / must avoid
 }
 
 impl Graph {
+    /// Name of the criterion held in `adjacency_matrix`
+    pub const PRIMARY_WEIGHT: &'static str = "jarak";
+
     /// Adjacency matrix (yeah, I know, it's not the most efficient way to represent a graph)
     pub fn new(adjacency_matrix: Vec<Vec<i32>>) -> Result<Self> {
         let size = adjacency_matrix.len();
@@ -30,8 +39,8 @@ impl Graph {
             }
         }
 
-        for i in 0..size {
-            if adjacency_matrix[i][i] != 0 {
+        for (i, row) in adjacency_matrix.iter().enumerate() {
+            if row[i] != 0 {
                 return Err(TSPError::InvalidGraph(
                     format!("Jarak dari node {} ke dirinya sendiri harus 0", i + 1)
                 ));
@@ -46,25 +55,285 @@ impl Graph {
             adjacency_matrix,
             size,
             node_names,
+            missing_edges: HashSet::new(),
+            weight_matrices: Vec::new(),
+            precedences: Vec::new(),
+            edge_constraints: EdgeConstraints::default(),
         })
     }
 
+    /// Matrix with absent arcs as None (the diagonal may be either 0 or None)
+    pub fn from_optional_matrix(matrix: Vec<Vec<Option<i32>>>) -> Result<Self> {
+        let missing: Vec<(usize, usize)> = matrix.iter().enumerate()
+            .flat_map(|(i, row)| row.iter().enumerate()
+                .filter(move |&(j, weight)| i != j && weight.is_none())
+                .map(move |(j, _)| (i, j)))
+            .collect();
+        let weights = matrix.into_iter()
+            .map(|row| row.into_iter().map(|weight| weight.unwrap_or(0)).collect())
+            .collect();
+
+        let mut graph = Self::new(weights)?;
+        graph.missing_edges.extend(missing);
+        Ok(graph)
+    }
+
     /// Adding node names to the graph
     pub fn with_node_names(adjacency_matrix: Vec<Vec<i32>>, names: Vec<String>) -> Result<Self> {
         let mut graph = Self::new(adjacency_matrix)?;
-        
-        if names.len() != graph.size {
+        graph.set_node_names(names)?;
+        Ok(graph)
+    }
+
+    pub fn set_node_names(&mut self, names: Vec<String>) -> Result<()> {
+        if names.len() != self.size {
             return Err(TSPError::InvalidGraph(
                 format!("Jumlah nama ({}) tidak sesuai dengan ukuran graf ({})", 
-                        names.len(), graph.size)
+                        names.len(), self.size)
             ));
         }
         
-        graph.node_names = names;
-        Ok(graph)
+        self.node_names = names;
+        Ok(())
+    }
+
+    /// Another weight per arc (e.g. travel time next to distance), named for multi-criteria solvers
+    pub fn add_weight_matrix(&mut self, name: &str, matrix: Vec<Vec<i32>>) -> Result<()> {
+        if name.is_empty() || self.criteria().contains(&name) {
+            return Err(TSPError::InvalidGraph(
+                format!("Nama matriks bobot '{}' kosong atau sudah dipakai", name)
+            ));
+        }
+        if matrix.len() != self.size || matrix.iter().any(|row| row.len() != self.size) {
+            return Err(TSPError::InvalidGraph(
+                format!("Matriks bobot '{}' harus berukuran {}x{}", name, self.size, self.size)
+            ));
+        }
+        if let Some(i) = (0..self.size).find(|&i| matrix[i][i] != 0) {
+            return Err(TSPError::InvalidGraph(
+                format!("Bobot '{}' dari node {} ke dirinya sendiri harus 0", name, i + 1)
+            ));
+        }
+
+        self.weight_matrices.push((name.to_string(), matrix));
+        Ok(())
+    }
+
+    /// Every criterion: the primary matrix first, then the named ones in insertion order
+    pub fn criteria(&self) -> Vec<&str> {
+        std::iter::once(Self::PRIMARY_WEIGHT)
+            .chain(self.weight_matrices.iter().map(|(name, _)| name.as_str()))
+            .collect()
+    }
+
+    pub fn find_criterion(&self, name: &str) -> Result<usize> {
+        self.criteria()
+            .iter()
+            .position(|&criterion| criterion == name.trim())
+            .ok_or_else(|| TSPError::InvalidGraph(format!("Matriks bobot '{}' tidak ditemukan", name.trim())))
+    }
+
+    /// Weight of the arc under criterion `index` (0 is `adjacency_matrix`)
+    pub fn criterion_weight(&self, index: usize, from: usize, to: usize) -> i32 {
+        match index {
+            0 => self.get_distance(from, to),
+            _ => self.weight_matrices[index - 1].1[from][to],
+        }
+    }
+
+    /// Remove the arc from -> to (no route may use it)
+    pub fn remove_edge(&mut self, from: usize, to: usize) -> Result<()> {
+        self.check_constraint_edge(from, to)?;
+        self.missing_edges.insert((from, to));
+        Ok(())
+    }
+
+    /// Add the arc from -> to, or change its weight
+    pub fn set_edge(&mut self, from: usize, to: usize, weight: i32) -> Result<()> {
+        self.check_constraint_edge(from, to)?;
+        self.adjacency_matrix[from][to] = weight;
+        self.missing_edges.remove(&(from, to));
+        Ok(())
+    }
+
+    /// Node index by name, or by 1-based number (as printed in the output)
+    pub fn find_node(&self, name: &str) -> Result<usize> {
+        let name = name.trim();
+        if let Some(index) = self.node_names.iter().position(|node_name| node_name == name) {
+            return Ok(index);
+        }
+
+        match name.parse::<usize>() {
+            Ok(number) if (1..=self.size).contains(&number) => Ok(number - 1),
+            _ => Err(TSPError::InvalidGraph(format!("Kota '{}' tidak ditemukan", name))),
+        }
+    }
+
+    /// Precedence constraint: `before` must be visited before `after` (the start of a tour counts as first)
+    pub fn add_precedence(&mut self, before: usize, after: usize) -> Result<()> {
+        if before >= self.size || after >= self.size {
+            return Err(TSPError::InvalidGraph(
+                format!("Precedence ({}, {}) di luar jangkauan graf", before, after)
+            ));
+        }
+        if before == after {
+            return Err(TSPError::InvalidGraph(
+                format!("{} tidak bisa mendahului dirinya sendiri", self.node_names[before])
+            ));
+        }
+
+        if !self.precedences.contains(&(before, after)) {
+            self.precedences.push((before, after));
+        }
+        Ok(())
     }
 
-    /// Distances between two nodes
+    /// The arc from -> to must be part of every route
+    pub fn require_edge(&mut self, from: usize, to: usize) -> Result<()> {
+        self.check_constraint_edge(from, to)?;
+        if !self.edge_constraints.required.contains(&(from, to)) {
+            self.edge_constraints.required.push((from, to));
+        }
+        Ok(())
+    }
+
+    /// The arc from -> to may not be used by any route
+    pub fn forbid_edge(&mut self, from: usize, to: usize) -> Result<()> {
+        self.check_constraint_edge(from, to)?;
+        self.edge_constraints.forbidden.insert((from, to));
+        Ok(())
+    }
+
+    fn check_constraint_edge(&self, from: usize, to: usize) -> Result<()> {
+        if from >= self.size || to >= self.size || from == to {
+            return Err(TSPError::InvalidGraph(
+                format!("Edge ({}, {}) tidak valid", from, to)
+            ));
+        }
+        Ok(())
+    }
+
+    /// Why the required/forbidden arcs can never be met together, if they cannot
+    pub fn edge_constraint_conflict(&self) -> Option<String> {
+        let name = |node: usize| self.node_names[node].as_str();
+        let required = &self.edge_constraints.required;
+
+        for &(from, to) in required {
+            if self.edge_constraints.forbidden.contains(&(from, to)) {
+                return Some(format!("edge {} -> {} wajib sekaligus terlarang", name(from), name(to)));
+            }
+            if !self.has_edge(from, to) {
+                return Some(format!("edge wajib {} -> {} tidak ada di graf", name(from), name(to)));
+            }
+        }
+
+        for (index, &(from, to)) in required.iter().enumerate() {
+            for &(other_from, other_to) in &required[index + 1..] {
+                if from == other_from {
+                    return Some(format!("{} hanya punya satu kota berikutnya, tetapi {} dan {} sama-sama wajib",
+                                        name(from), name(to), name(other_to)));
+                }
+                if to == other_to {
+                    return Some(format!("{} hanya punya satu kota sebelumnya, tetapi {} dan {} sama-sama wajib",
+                                        name(to), name(from), name(other_from)));
+                }
+            }
+        }
+
+        // Following the required successors from any node must not close a cycle shorter than the tour
+        for &(start, _) in required {
+            let mut cycle = vec![start];
+            let mut current = start;
+            while let Some(&(_, next)) = required.iter().find(|&&(from, _)| from == current) {
+                cycle.push(next);
+                if next == start {
+                    if cycle.len() <= self.size {
+                        return Some(format!("edge wajib membentuk subtour {}",
+                                            cycle.iter().map(|&v| name(v)).collect::<Vec<_>>().join(" -> ")));
+                    }
+                    break;
+                }
+                if cycle.len() > self.size {
+                    break;
+                }
+                current = next;
+            }
+        }
+
+        None
+    }
+
+    /// Nodes that must be visited before `node` (direct constraints only)
+    pub fn predecessors_mask(&self, node: usize) -> u64 {
+        self.precedences
+            .iter()
+            .filter(|&&(_, after)| after == node)
+            .fold(0u64, |mask, &(before, _)| mask | (1u64 << before))
+    }
+
+    /// A cycle of precedences as [a, b, ..., a], if any (such constraints can never be met)
+    pub fn precedence_cycle(&self) -> Option<Vec<usize>> {
+        // 0 = unvisited, 1 = on the DFS stack, 2 = finished
+        let mut state = vec![0u8; self.size];
+        let mut stack = Vec::new();
+
+        for root in 0..self.size {
+            if state[root] == 0 {
+                if let Some(cycle) = self.find_cycle_from(root, &mut state, &mut stack) {
+                    return Some(cycle);
+                }
+            }
+        }
+        None
+    }
+
+    fn find_cycle_from(&self, node: usize, state: &mut [u8], stack: &mut Vec<usize>) -> Option<Vec<usize>> {
+        state[node] = 1;
+        stack.push(node);
+
+        for &(_, next) in self.precedences.iter().filter(|&&(before, _)| before == node) {
+            match state[next] {
+                0 => {
+                    if let Some(cycle) = self.find_cycle_from(next, state, stack) {
+                        return Some(cycle);
+                    }
+                }
+                1 => {
+                    let start = stack.iter().position(|&v| v == next).unwrap_or(0);
+                    let mut cycle = stack[start..].to_vec();
+                    cycle.push(next);
+                    return Some(cycle);
+                }
+                _ => {}
+            }
+        }
+
+        stack.pop();
+        state[node] = 2;
+        None
+    }
+
+    /// Precedences broken by a route, ordered by first visit (unvisited nodes are ignored)
+    pub fn precedence_violations(&self, path: &[usize]) -> Vec<(usize, usize)> {
+        let mut first_position = vec![usize::MAX; self.size];
+        for (position, &node) in path.iter().enumerate() {
+            if node < self.size && first_position[node] == usize::MAX {
+                first_position[node] = position;
+            }
+        }
+
+        self.precedences
+            .iter()
+            .copied()
+            .filter(|&(before, after)| {
+                first_position[before] != usize::MAX
+                    && first_position[after] != usize::MAX
+                    && first_position[after] < first_position[before]
+            })
+            .collect()
+    }
+
+    /// Distances between two nodes (meaningless for a missing edge, see `edge_weight`)
     pub fn get_distance(&self, from: usize, to: usize) -> i32 {
         if from >= self.size || to >= self.size {
             panic!("Node index out of bounds: from={}, to={}, size={}", from, to, self.size);
@@ -72,18 +341,25 @@ impl Graph {
         self.adjacency_matrix[from][to]
     }
 
+    /// Structural checks only: missing edges are allowed, see `FeasibilityReport` for whether a
+    /// route can still exist
     pub fn validate_for_tsp(&self) -> Result<()> {
-        for i in 0..self.size {
-            for j in 0..self.size {
-                if i != j && self.adjacency_matrix[i][j] <= 0 {
-                    return Err(TSPError::InvalidGraph(
-                        format!("Tidak ada path dari {} ke {} (nilai: {})", 
-                                self.node_names[i], self.node_names[j], 
-                                self.adjacency_matrix[i][j])
-                    ));
-                }
-            }
+        if let Some(cycle) = self.precedence_cycle() {
+            return Err(TSPError::InvalidGraph(
+                format!("Precedence siklik: {}",
+                        cycle.iter()
+                            .map(|&v| self.node_names[v].as_str())
+                            .collect::<Vec<_>>()
+                            .join(" -> "))
+            ));
         }
+
+        if let Some(conflict) = self.edge_constraint_conflict() {
+            return Err(TSPError::InvalidGraph(
+                format!("Batasan edge tidak dapat dipenuhi: {}", conflict)
+            ));
+        }
+
         Ok(())
     }
 
@@ -106,7 +382,7 @@ impl Graph {
             for (j, &val) in row.iter().enumerate() {
                 let cell_content = if i == j {
                     "0".dimmed().to_string()
-                } else if val == i32::MAX || val <= 0 {
+                } else if !self.has_edge(i, j) {
                     "∞".red().to_string()
                 } else {
                     val.to_string()
@@ -117,6 +393,33 @@ impl Graph {
         }
 
         table.printstd();
+
+        if !self.precedences.is_empty() {
+            println!("Urutan wajib:");
+            for &(before, after) in &self.precedences {
+                println!("  {} sebelum {}", 
+                        self.node_names[before].bright_cyan(), 
+                        self.node_names[after].bright_cyan());
+            }
+        }
+        if !self.edge_constraints.required.is_empty() {
+            println!("Edge wajib:");
+            for &(from, to) in &self.edge_constraints.required {
+                println!("  {} -> {}", 
+                        self.node_names[from].bright_cyan(), 
+                        self.node_names[to].bright_cyan());
+            }
+        }
+        if !self.edge_constraints.forbidden.is_empty() {
+            let mut forbidden: Vec<(usize, usize)> = self.edge_constraints.forbidden.iter().copied().collect();
+            forbidden.sort_unstable();
+            println!("Edge terlarang:");
+            for (from, to) in forbidden {
+                println!("  {} -> {}", 
+                        self.node_names[from].bright_cyan(), 
+                        self.node_names[to].bright_cyan());
+            }
+        }
         println!();
     }
 
@@ -127,7 +430,7 @@ impl Graph {
         let mut edge_count = 0;
         for i in 0..self.size {
             for j in 0..self.size {
-                if i != j && self.adjacency_matrix[i][j] > 0 {
+                if self.has_edge(i, j) {
                     println!("  {} {} {} {}", 
                            self.node_names[i].bright_cyan(),
                            "-->".yellow(),
@@ -158,51 +461,202 @@ impl Graph {
                 ));
             }
 
-            let cost = self.get_distance(from, to);
-            if cost <= 0 {
-                return Err(TSPError::InvalidPath(
-                    format!("Tidak ada edge dari {} ke {}", 
-                            self.node_names[from], self.node_names[to])
-                ));
-            }
+            let cost = self.edge_weight(from, to).ok_or_else(|| TSPError::InvalidPath(
+                format!("Tidak ada edge dari {} ke {}", 
+                        self.node_names[from], self.node_names[to])
+            ))?;
             total_cost += cost;
         }
 
         Ok(total_cost)
     }
 
+    /// Edge existence (any weight counts, only explicitly missing arcs, self-loops and nodes outside
+    /// the graph do not)
+    pub fn has_edge(&self, from: usize, to: usize) -> bool {
+        from < self.size && to < self.size && from != to && !self.missing_edges.contains(&(from, to))
+    }
+
+    /// A route may move from -> to: the edge exists and the edge constraints allow it
+    pub fn can_travel(&self, from: usize, to: usize) -> bool {
+        self.has_edge(from, to) && self.edge_constraints.allows(from, to)
+    }
+
+    /// Weight of an existing edge, None when it is missing (infinite)
+    pub fn edge_weight(&self, from: usize, to: usize) -> Option<i32> {
+        self.has_edge(from, to).then(|| self.adjacency_matrix[from][to])
+    }
+
     /// Hamilton tour validation
     pub fn is_valid_tour(&self, path: &[usize]) -> bool {
-        if path.len() != self.size + 1 {
-            return false;
+        self.verify_tour(path, None).is_valid()
+    }
+
+    /// Open Hamilton path validation (every node exactly once, no return)
+    pub fn is_valid_open_path(&self, path: &[usize]) -> bool {
+        self.verify_open_path(path, None).is_valid()
+    }
+
+    /// Hamilton tour verification, reporting every problem found instead of a bare bool
+    pub fn verify_tour(&self, path: &[usize], claimed_cost: Option<i32>) -> TourVerification {
+        self.check_route(path, true, None, None, claimed_cost)
+    }
+
+    /// Open Hamilton path verification (same checks as `verify_tour`, without the return)
+    pub fn verify_open_path(&self, path: &[usize], claimed_cost: Option<i32>) -> TourVerification {
+        self.check_route(path, false, None, None, claimed_cost)
+    }
+
+    /// Verification against a route mode, including its fixed start and end cities
+    pub fn verify_route(&self, path: &[usize], mode: &RouteMode, claimed_cost: Option<i32>) -> TourVerification {
+        self.check_route(path, mode.is_closed(), mode.start(), mode.end(), claimed_cost)
+    }
+
+    fn check_route(
+        &self,
+        path: &[usize],
+        closed: bool,
+        start: Option<usize>,
+        end: Option<usize>,
+        claimed_cost: Option<i32>,
+    ) -> TourVerification {
+        let mut issues = Vec::new();
+
+        let expected_length = if closed { self.size + 1 } else { self.size };
+        if path.len() != expected_length {
+            issues.push(TourIssue::WrongLength {
+                expected: expected_length,
+                actual: path.len(),
+            });
+        }
+
+        if path.is_empty() {
+            return TourVerification { issues, actual_cost: None, actual_bottleneck: None };
+        }
+
+        let last = path.len() - 1;
+        let is_closed = closed && path.len() >= 2 && path[0] == path[last];
+        if closed && !is_closed {
+            issues.push(TourIssue::NotClosed {
+                first: self.describe_node(path[0]),
+                last: self.describe_node(path[last]),
+                position: last,
+            });
+        }
+
+        if let Some(start) = start.filter(|&start| path[0] != start) {
+            issues.push(TourIssue::WrongStart {
+                expected: self.describe_node(start),
+                actual: self.describe_node(path[0]),
+            });
+        }
+        if let Some(end) = end.filter(|&end| !closed && path[last] != end) {
+            issues.push(TourIssue::WrongEnd {
+                expected: self.describe_node(end),
+                actual: self.describe_node(path[last]),
+                position: last,
+            });
+        }
+
+        // The closing node is expected to repeat the first one, so it is not part of the body
+        let body = if is_closed { &path[..last] } else { path };
+        let mut first_seen: Vec<Option<usize>> = vec![None; self.size];
+        for (position, &node) in body.iter().enumerate() {
+            if node >= self.size {
+                issues.push(TourIssue::OutOfRange { index: node, position });
+                continue;
+            }
+
+            match first_seen[node] {
+                Some(first_position) => issues.push(TourIssue::DuplicateNode {
+                    name: self.node_names[node].clone(),
+                    position,
+                    first_position,
+                }),
+                None => first_seen[node] = Some(position),
+            }
+        }
+        if is_closed && path[last] >= self.size {
+            issues.push(TourIssue::OutOfRange { index: path[last], position: last });
         }
 
-        if path[0] != path[path.len() - 1] {
-            return false;
+        for (node, seen) in first_seen.iter().enumerate() {
+            if seen.is_none() {
+                issues.push(TourIssue::MissingNode { name: self.node_names[node].clone() });
+            }
         }
 
-        let mut visited = vec![false; self.size];
-        for &node in &path[..path.len() - 1] {
-            if node >= self.size || visited[node] {
-                return false;
+        let mut total_cost = 0;
+        let mut bottleneck = i32::MIN;
+        let mut cost_is_complete = true;
+        for (position, window) in path.windows(2).enumerate() {
+            let (from, to) = (window[0], window[1]);
+            if from >= self.size || to >= self.size {
+                cost_is_complete = false;
+                continue;
+            }
+
+            if self.has_edge(from, to) {
+                total_cost += self.adjacency_matrix[from][to];
+                bottleneck = bottleneck.max(self.adjacency_matrix[from][to]);
+            } else {
+                cost_is_complete = false;
+                issues.push(TourIssue::MissingEdge {
+                    from: self.node_names[from].clone(),
+                    to: self.node_names[to].clone(),
+                    position,
+                });
             }
-            visited[node] = true;
         }
 
-        visited.iter().all(|&v| v)
+        for (before, after) in self.precedence_violations(body) {
+            issues.push(TourIssue::PrecedenceViolated {
+                before: self.node_names[before].clone(),
+                after: self.node_names[after].clone(),
+            });
+        }
+
+        for (from, to) in self.edge_constraints.missing_required(path) {
+            issues.push(TourIssue::RequiredEdgeMissing {
+                from: self.node_names[from].clone(),
+                to: self.node_names[to].clone(),
+            });
+        }
+        for (from, to) in self.edge_constraints.forbidden_used(path) {
+            issues.push(TourIssue::ForbiddenEdgeUsed {
+                from: self.node_names[from].clone(),
+                to: self.node_names[to].clone(),
+            });
+        }
+
+        let actual_cost = if cost_is_complete { Some(total_cost) } else { None };
+        let actual_bottleneck = if cost_is_complete { Some(bottleneck) } else { None };
+        if let (Some(claimed), Some(actual)) = (claimed_cost, actual_cost) {
+            if claimed != actual {
+                issues.push(TourIssue::CostMismatch { claimed, actual });
+            }
+        }
+
+        TourVerification { issues, actual_cost, actual_bottleneck }
+    }
+
+    fn describe_node(&self, node: usize) -> String {
+        self.node_names
+            .get(node)
+            .cloned()
+            .unwrap_or_else(|| format!("<indeks {}>", node))
     }
 
     /// Stats
     pub fn get_stats(&self) -> GraphStats {
         let mut total_edges = 0;
         let mut min_edge = i32::MAX;
-        let mut max_edge = 0;
+        let mut max_edge = i32::MIN;
         let mut total_weight = 0;
 
         for i in 0..self.size {
             for j in 0..self.size {
-                if i != j && self.adjacency_matrix[i][j] > 0 {
-                    let weight = self.adjacency_matrix[i][j];
+                if let Some(weight) = self.edge_weight(i, j) {
                     total_edges += 1;
                     total_weight += weight;
                     min_edge = min_edge.min(weight);
@@ -221,7 +675,7 @@ impl Graph {
             nodes: self.size,
             edges: total_edges,
             min_edge_weight: if min_edge == i32::MAX { 0 } else { min_edge },
-            max_edge_weight: max_edge,
+            max_edge_weight: if max_edge == i32::MIN { 0 } else { max_edge },
             avg_edge_weight: avg_edge,
             total_weight,
         }
@@ -248,4 +702,201 @@ impl std::fmt::Display for GraphStats {
             self.max_edge_weight, self.avg_edge_weight, self.total_weight
         )
     }
-}
\ No newline at end of file
+}
+
+/// Single problem found by `Graph::verify_tour` (positions are 0-based indices into the path)
+#[derive(Debug, Clone, PartialEq)]
+pub enum TourIssue {
+    WrongLength { expected: usize, actual: usize },
+    NotClosed { first: String, last: String, position: usize },
+    WrongStart { expected: String, actual: String },
+    WrongEnd { expected: String, actual: String, position: usize },
+    DuplicateNode { name: String, position: usize, first_position: usize },
+    MissingNode { name: String },
+    OutOfRange { index: usize, position: usize },
+    MissingEdge { from: String, to: String, position: usize },
+    PrecedenceViolated { before: String, after: String },
+    RequiredEdgeMissing { from: String, to: String },
+    ForbiddenEdgeUsed { from: String, to: String },
+    CostMismatch { claimed: i32, actual: i32 },
+    BottleneckMismatch { claimed: i32, actual: i32 },
+}
+
+impl std::fmt::Display for TourIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TourIssue::WrongLength { expected, actual } => write!(f,
+                "Panjang path {} node, seharusnya {} node", actual, expected),
+            TourIssue::NotClosed { first, last, position } => write!(f,
+                "Tour tidak kembali ke awal: dimulai di {} tetapi berakhir di {} (posisi {})",
+                first, last, position + 1),
+            TourIssue::WrongStart { expected, actual } => write!(f,
+                "Rute harus dimulai di {} tetapi dimulai di {} (posisi 1)", expected, actual),
+            TourIssue::WrongEnd { expected, actual, position } => write!(f,
+                "Rute harus berakhir di {} tetapi berakhir di {} (posisi {})",
+                expected, actual, position + 1),
+            TourIssue::DuplicateNode { name, position, first_position } => write!(f,
+                "{} dikunjungi lebih dari sekali (posisi {} dan {})",
+                name, first_position + 1, position + 1),
+            TourIssue::MissingNode { name } => write!(f,
+                "{} tidak pernah dikunjungi", name),
+            TourIssue::OutOfRange { index, position } => write!(f,
+                "Indeks node {} di posisi {} di luar jangkauan graf", index, position + 1),
+            TourIssue::MissingEdge { from, to, position } => write!(f,
+                "Tidak ada edge dari {} ke {} (posisi {})",
+                from, to, position + 1),
+            TourIssue::PrecedenceViolated { before, after } => write!(f,
+                "{} harus dikunjungi sebelum {}", before, after),
+            TourIssue::RequiredEdgeMissing { from, to } => write!(f,
+                "Edge wajib {} -> {} tidak dilalui", from, to),
+            TourIssue::ForbiddenEdgeUsed { from, to } => write!(f,
+                "Edge terlarang {} -> {} dilalui", from, to),
+            TourIssue::CostMismatch { claimed, actual } => write!(f,
+                "Biaya yang diklaim ({}) berbeda dengan biaya sebenarnya ({})", claimed, actual),
+            TourIssue::BottleneckMismatch { claimed, actual } => write!(f,
+                "Bottleneck yang diklaim ({}) berbeda dengan edge terpanjang sebenarnya ({})", claimed, actual),
+        }
+    }
+}
+
+/// Verification report (every issue found in a tour, plus its actual cost when computable)
+#[derive(Debug, Clone, Default)]
+pub struct TourVerification {
+    pub issues: Vec<TourIssue>,
+    pub actual_cost: Option<i32>,
+    pub actual_bottleneck: Option<i32>, // longest edge
+}
+
+impl TourVerification {
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl std::fmt::Display for TourVerification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "=== TOUR VERIFICATION ===")?;
+        match self.actual_cost {
+            Some(cost) => writeln!(f, "Actual Cost: {}", cost)?,
+            None => writeln!(f, "Actual Cost: N/A")?,
+        }
+        if let Some(bottleneck) = self.actual_bottleneck {
+            writeln!(f, "Actual Bottleneck: {}", bottleneck)?;
+        }
+
+        if self.issues.is_empty() {
+            writeln!(f, "Status: [VALID]")?;
+        } else {
+            for issue in &self.issues {
+                writeln!(f, "  [ERROR] {}", issue)?;
+            }
+            writeln!(f, "Status: [INVALID]")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_graph() -> Graph {
+        let matrix = vec![
+            vec![0, 10, 15, 20],
+            vec![5,  0,  9, 10],
+            vec![6, 13,  0, 12],
+            vec![8,  8,  9,  0],
+        ];
+        let names = vec!["A", "B", "C", "D"].into_iter().map(String::from).collect();
+        let mut graph = Graph::with_node_names(matrix, names).unwrap();
+        graph.remove_edge(3, 2).unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_verify_valid_tour() {
+        let graph = sample_graph();
+
+        let report = graph.verify_tour(&[0, 1, 2, 3, 0], Some(39));
+        assert!(report.is_valid());
+        assert_eq!(report.actual_cost, Some(39));
+
+        let report = graph.verify_tour(&[0, 1, 2, 3, 0], Some(35));
+        assert_eq!(report.issues, vec![TourIssue::CostMismatch { claimed: 35, actual: 39 }]);
+    }
+
+    #[test]
+    fn test_verify_reports_every_issue() {
+        let graph = sample_graph();
+        let report = graph.verify_tour(&[0, 1, 1, 5, 3], None);
+
+        assert!(!report.is_valid());
+        assert_eq!(report.actual_cost, None);
+        assert!(report.issues.contains(&TourIssue::NotClosed {
+            first: "A".to_string(), last: "D".to_string(), position: 4,
+        }));
+        assert!(report.issues.contains(&TourIssue::DuplicateNode {
+            name: "B".to_string(), position: 2, first_position: 1,
+        }));
+        assert!(report.issues.contains(&TourIssue::OutOfRange { index: 5, position: 3 }));
+        assert!(report.issues.contains(&TourIssue::MissingNode { name: "C".to_string() }));
+    }
+
+    #[test]
+    fn test_verify_missing_edge() {
+        let graph = sample_graph();
+        let report = graph.verify_tour(&[0, 1, 3, 2, 0], None);
+
+        assert_eq!(report.issues, vec![TourIssue::MissingEdge {
+            from: "D".to_string(), to: "C".to_string(), position: 2,
+        }]);
+        assert!(!graph.is_valid_tour(&[0, 1, 3, 2, 0]));
+    }
+
+    #[test]
+    fn test_find_node_by_name_or_number() {
+        let graph = sample_graph();
+
+        assert_eq!(graph.find_node("C").unwrap(), 2);
+        assert_eq!(graph.find_node(" D ").unwrap(), 3);
+        assert_eq!(graph.find_node("1").unwrap(), 0);
+        assert!(graph.find_node("0").is_err());
+        assert!(graph.find_node("Z").is_err());
+    }
+
+    #[test]
+    fn test_verify_open_path() {
+        let graph = sample_graph();
+
+        let report = graph.verify_open_path(&[2, 0, 1, 3], Some(26));
+        assert!(report.is_valid());
+
+        let report = graph.verify_route(&[2, 0, 1, 3], &RouteMode::PathBetween { start: 0, end: 3 }, None);
+        assert_eq!(report.issues, vec![TourIssue::WrongStart {
+            expected: "A".to_string(), actual: "C".to_string(),
+        }]);
+
+        // A closed tour is one node too long for an open path and repeats its start
+        let report = graph.verify_open_path(&[0, 1, 2, 3, 0], None);
+        assert!(report.issues.contains(&TourIssue::WrongLength { expected: 4, actual: 5 }));
+        assert!(report.issues.contains(&TourIssue::DuplicateNode {
+            name: "A".to_string(), position: 4, first_position: 0,
+        }));
+    }
+
+    #[test]
+    fn test_precedence_cycle_is_named() {
+        let mut graph = sample_graph();
+        graph.add_precedence(0, 1).unwrap();
+        graph.add_precedence(1, 2).unwrap();
+        assert_eq!(graph.precedence_cycle(), None);
+        assert_eq!(graph.precedence_violations(&[0, 2, 1, 3, 0]), vec![(1, 2)]);
+
+        graph.add_precedence(2, 0).unwrap();
+        assert_eq!(graph.precedence_cycle(), Some(vec![0, 1, 2, 0]));
+
+        let message = graph.validate_for_tsp().unwrap_err().to_string();
+        assert!(message.contains("A -> B -> C -> A"), "{}", message);
+    }
+}
@@ -0,0 +1,370 @@
+/// Libraries
+use crate::heuristics::local_search;
+use crate::tsp_solver::SolverStats;
+use crate::{Graph, Result, TSPError};
+use std::time::Instant;
+
+/// Largest number of clusters handled by the exact DP
+const MAX_EXACT_CLUSTERS: usize = 16;
+
+/// Upper bound on the alternating order / city-choice passes of the heuristic
+const MAX_PASSES: usize = 1_000;
+
+/// Equivalent locations: the tour visits exactly one of `cities`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CityCluster {
+    pub name: String,
+    pub cities: Vec<usize>,
+}
+
+/// Result
+#[derive(Debug)]
+pub struct GTSPSolution {
+    pub path: Vec<usize>,
+    pub cost: i32,
+    pub clusters: Vec<CityCluster>, // including the singletons added for unlisted cities
+    pub stats: SolverStats,
+    pub is_exact: bool,
+    pub is_valid: bool,
+}
+
+impl GTSPSolution {
+    /// City chosen for each cluster (None if the path misses the cluster)
+    pub fn chosen_cities(&self) -> Vec<Option<usize>> {
+        self.clusters
+            .iter()
+            .map(|cluster| cluster.cities.iter().copied().find(|city| self.path.contains(city)))
+            .collect()
+    }
+}
+
+/// Generalized TSP: one city per cluster, cities missing from every cluster form their own
+pub struct GTSPSolver {
+    graph: Graph,
+    clusters: Vec<CityCluster>,
+    cluster_of: Vec<usize>,
+}
+
+impl GTSPSolver {
+    pub fn new(graph: Graph, clusters: Vec<CityCluster>) -> Result<Self> {
+        graph.validate_for_tsp()?;
+
+        let mut cluster_of = vec![usize::MAX; graph.size];
+        for (index, cluster) in clusters.iter().enumerate() {
+            if cluster.cities.is_empty() {
+                return Err(TSPError::InvalidGraph(format!("Cluster '{}' kosong", cluster.name)));
+            }
+            for &city in &cluster.cities {
+                if city >= graph.size {
+                    return Err(TSPError::InvalidGraph(
+                        format!("Kota {} di cluster '{}' di luar jangkauan graf", city, cluster.name)
+                    ));
+                }
+                if cluster_of[city] != usize::MAX {
+                    return Err(TSPError::InvalidGraph(
+                        format!("{} termasuk lebih dari satu cluster ('{}' dan '{}')",
+                                graph.node_names[city], clusters[cluster_of[city]].name, cluster.name)
+                    ));
+                }
+                cluster_of[city] = index;
+            }
+        }
+
+        let mut clusters = clusters;
+        for (city, &index) in cluster_of.iter().enumerate() {
+            if index == usize::MAX {
+                clusters.push(CityCluster {
+                    name: graph.node_names[city].clone(),
+                    cities: vec![city],
+                });
+            }
+        }
+
+        // The tour starts in the cluster of the first city
+        let first = clusters.iter().position(|cluster| cluster.cities.contains(&0)).unwrap_or(0);
+        clusters.swap(0, first);
+        for (index, cluster) in clusters.iter().enumerate() {
+            for &city in &cluster.cities {
+                cluster_of[city] = index;
+            }
+        }
+
+        if clusters.len() < 2 {
+            return Err(TSPError::SolverError("Diperlukan minimal 2 cluster".to_string()));
+        }
+
+        Ok(GTSPSolver {
+            graph,
+            clusters,
+            cluster_of,
+        })
+    }
+
+    /// Held-Karp over cluster subsets: f(S, v) = cheapest path from the start city through the
+    /// clusters in S ending at v, for every choice of start city in the first cluster
+    pub fn solve(&self) -> Result<GTSPSolution> {
+        let k = self.clusters.len();
+        if k > MAX_EXACT_CLUSTERS {
+            return Err(TSPError::SolverError(
+                format!("Terlalu banyak cluster (>{}) untuk DP eksak, gunakan heuristik", MAX_EXACT_CLUSTERS)
+            ));
+        }
+
+        let mut stats = SolverStats {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        };
+
+        let n = self.graph.size;
+        let full = (1usize << (k - 1)) - 1;
+        let bit = |city: usize| 1usize << (self.cluster_of[city] - 1);
+        let mut best: Option<(i32, Vec<usize>)> = None;
+
+        for &start in &self.clusters[0].cities {
+            // (cost, previous city) per (cluster mask, last city)
+            let mut dp: Vec<Vec<Option<(i32, usize)>>> = vec![vec![None; n]; full + 1];
+            for v in (0..n).filter(|&v| self.cluster_of[v] != 0 && self.graph.can_travel(start, v)) {
+                dp[bit(v)][v] = Some((self.graph.get_distance(start, v), start));
+            }
+
+            for mask in 1..=full {
+                for v in 0..n {
+                    let Some((cost, _)) = dp[mask][v] else {
+                        continue;
+                    };
+                    for w in (0..n).filter(|&w| self.cluster_of[w] != 0 && mask & bit(w) == 0) {
+                        if !self.graph.can_travel(v, w) {
+                            continue;
+                        }
+                        let total = cost + self.graph.get_distance(v, w);
+                        let entry = &mut dp[mask | bit(w)][w];
+                        if entry.is_none_or(|(current, _)| total < current) {
+                            *entry = Some((total, v));
+                        }
+                        stats.states_computed += 1;
+                    }
+                }
+            }
+
+            let closing = (0..n)
+                .filter_map(|v| {
+                    let (cost, _) = dp[full][v]?;
+                    self.graph.can_travel(v, start).then(|| (cost + self.graph.get_distance(v, start), v))
+                })
+                .min();
+            let Some((cost, mut last)) = closing else {
+                continue;
+            };
+            if best.as_ref().is_some_and(|(best_cost, _)| *best_cost <= cost) {
+                continue;
+            }
+
+            let mut path = vec![start];
+            let mut mask = full;
+            while mask != 0 {
+                path.push(last);
+                let (_, previous) = dp[mask][last].expect("reconstructed state exists");
+                mask &= !bit(last);
+                last = previous;
+            }
+            path.push(start);
+            path.reverse();
+            best = Some((cost, path));
+        }
+
+        let (cost, path) = best.ok_or_else(|| {
+            TSPError::SolverError("Tidak ada tour yang melewati setiap cluster".to_string())
+        })?;
+        Ok(self.build_solution(path, cost, stats, true))
+    }
+
+    /// Nearest neighbour over clusters, then alternating local search on the cluster order and
+    /// re-selection of the city inside each cluster
+    pub fn solve_heuristic(&self) -> Result<GTSPSolution> {
+        let mut stats = SolverStats {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        };
+
+        let mut best: Option<(i32, Vec<usize>)> = None;
+        for &start in &self.clusters[0].cities {
+            let Some(route) = self.nearest_neighbour(start) else {
+                continue;
+            };
+            let Some((cost, route)) = self.improve(route, &mut stats) else {
+                continue;
+            };
+            if best.as_ref().is_none_or(|(best_cost, _)| cost < *best_cost) {
+                best = Some((cost, route));
+            }
+        }
+
+        let (cost, path) = best.ok_or_else(|| {
+            TSPError::SolverError("Heuristik tidak menemukan tour yang valid".to_string())
+        })?;
+        Ok(self.build_solution(path, cost, stats, false))
+    }
+
+    fn nearest_neighbour(&self, start: usize) -> Option<Vec<usize>> {
+        let mut covered = vec![false; self.clusters.len()];
+        covered[self.cluster_of[start]] = true;
+        let mut route = vec![start];
+        let mut current = start;
+
+        while let Some(next) = (0..self.graph.size)
+            .filter(|&v| !covered[self.cluster_of[v]] && self.graph.can_travel(current, v))
+            .min_by_key(|&v| (self.graph.get_distance(current, v), v))
+        {
+            covered[self.cluster_of[next]] = true;
+            route.push(next);
+            current = next;
+        }
+
+        if covered.iter().any(|&c| !c) {
+            return None;
+        }
+        route.push(start);
+        Some(route)
+    }
+
+    fn improve(&self, mut route: Vec<usize>, stats: &mut SolverStats) -> Option<(i32, Vec<usize>)> {
+        let mut cost = self.route_cost(&route)?;
+
+        for _ in 0..MAX_PASSES {
+            let movable = 1..route.len() - 1;
+            (cost, route) = local_search(route, movable, |r| self.route_cost(r), stats)?;
+
+            // Swap a city for another member of its cluster (the start also moves the return)
+            let mut improved = false;
+            for position in 0..route.len() - 1 {
+                for &alternative in &self.clusters[self.cluster_of[route[position]]].cities {
+                    let mut candidate = route.clone();
+                    candidate[position] = alternative;
+                    if position == 0 {
+                        *candidate.last_mut()? = alternative;
+                    }
+                    stats.states_computed += 1;
+
+                    if let Some(candidate_cost) = self.route_cost(&candidate).filter(|&c| c < cost) {
+                        route = candidate;
+                        cost = candidate_cost;
+                        improved = true;
+                    }
+                }
+            }
+
+            if !improved {
+                break;
+            }
+        }
+
+        Some((cost, route))
+    }
+
+    fn route_cost(&self, route: &[usize]) -> Option<i32> {
+        route.windows(2).try_fold(0, |total, w| {
+            self.graph.can_travel(w[0], w[1]).then(|| total + self.graph.get_distance(w[0], w[1]))
+        })
+    }
+
+    fn build_solution(&self, path: Vec<usize>, cost: i32, mut stats: SolverStats, is_exact: bool) -> GTSPSolution {
+        // Closed tour, every cluster exactly once, every edge present
+        let mut visits = vec![0usize; self.clusters.len()];
+        for &city in &path[..path.len() - 1] {
+            visits[self.cluster_of[city]] += 1;
+        }
+        let is_valid = path.first() == path.last()
+            && visits.iter().all(|&count| count == 1)
+            && self.route_cost(&path) == Some(cost);
+
+        stats.solve_duration = stats.start_time.map(|t| t.elapsed());
+
+        GTSPSolution {
+            path,
+            cost,
+            clusters: self.clusters.clone(),
+            stats,
+            is_exact,
+            is_valid,
+        }
+    }
+}
+
+impl std::fmt::Display for GTSPSolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "=== GENERALIZED TSP SOLUTION ===")?;
+        writeln!(f, "Method: {}", if self.is_exact { "Exact DP" } else { "Heuristic" })?;
+        writeln!(f, "Clusters: {}", self.clusters.len())?;
+        writeln!(f, "Cost: {}", self.cost)?;
+        writeln!(f, "Path: {}",
+                self.path.iter()
+                    .map(|&i| (i + 1).to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> "))?;
+        writeln!(f, "Valid: {}", if self.is_valid { "Yes" } else { "No" })?;
+        writeln!(f, "{}", self.stats)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Document example plus E, a cheaper branch of B
+    fn branch_graph() -> Graph {
+        let matrix = vec![
+            vec![0, 10, 15, 20, 3],
+            vec![5,  0,  9, 10, 4],
+            vec![6, 13,  0, 12, 2],
+            vec![8,  8,  9,  0, 5],
+            vec![3,  4,  2,  5, 0],
+        ];
+        Graph::new(matrix).unwrap()
+    }
+
+    fn cluster(name: &str, cities: &[usize]) -> CityCluster {
+        CityCluster { name: name.to_string(), cities: cities.to_vec() }
+    }
+
+    #[test]
+    fn test_gtsp_picks_cheaper_branch() -> Result<()> {
+        let solver = GTSPSolver::new(branch_graph(), vec![cluster("B", &[1, 4])])?;
+
+        let exact = solver.solve()?;
+        assert!(exact.is_valid);
+        assert_eq!(exact.cost, 23);
+        assert_eq!(exact.path, vec![0, 4, 3, 2, 0]);
+        assert_eq!(exact.clusters.len(), 4);
+        assert_eq!(exact.clusters[1].name, "B");
+        assert_eq!(exact.chosen_cities()[1], Some(4));
+
+        let heuristic = solver.solve_heuristic()?;
+        assert!(heuristic.is_valid);
+        assert!(heuristic.cost >= exact.cost);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gtsp_rejects_overlapping_clusters() {
+        let clusters = vec![cluster("X", &[1, 4]), cluster("Y", &[4, 2])];
+        let message = GTSPSolver::new(branch_graph(), clusters).err().unwrap().to_string();
+        assert!(message.contains("Kota_5"), "{}", message);
+    }
+
+    #[test]
+    fn test_gtsp_forbidden_edge() -> Result<()> {
+        let mut graph = branch_graph();
+        graph.forbid_edge(4, 3)?;
+        let solver = GTSPSolver::new(graph, vec![cluster("B", &[1, 4])])?;
+
+        for solution in [solver.solve()?, solver.solve_heuristic()?] {
+            assert!(solution.is_valid);
+            assert!(solution.cost > 23);
+            assert!(!solution.path.windows(2).any(|w| w == [4, 3]));
+        }
+
+        Ok(())
+    }
+}
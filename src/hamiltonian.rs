@@ -0,0 +1,275 @@
+/// Libraries
+use crate::tsp_solver::SolverStats;
+use crate::{Graph, Result, TSPError};
+use std::time::Instant;
+
+/// Largest graph handled (2^(n-1) * n counters are kept)
+const MAX_SIZE: usize = 16;
+
+/// Unsigned integer of arbitrary size, just enough for counting: addition, small division, decimal output
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BigUint {
+    limbs: Vec<u32>, // little-endian base 2^32, no trailing zeros (zero is empty)
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        BigUint::default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Quotient and remainder of a division by a small divisor
+    pub fn div_rem_small(&self, divisor: u32) -> (BigUint, u32) {
+        assert!(divisor != 0, "pembagian dengan nol");
+
+        let mut limbs = vec![0u32; self.limbs.len()];
+        let mut remainder = 0u64;
+        for (i, &limb) in self.limbs.iter().enumerate().rev() {
+            let current = (remainder << 32) | limb as u64;
+            limbs[i] = (current / divisor as u64) as u32;
+            remainder = current % divisor as u64;
+        }
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        (BigUint { limbs }, remainder as u32)
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        let mut limbs = vec![value as u32, (value >> 32) as u32];
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        BigUint { limbs }
+    }
+}
+
+impl std::ops::AddAssign<&BigUint> for BigUint {
+    fn add_assign(&mut self, other: &BigUint) {
+        if self.limbs.len() < other.limbs.len() {
+            self.limbs.resize(other.limbs.len(), 0);
+        }
+
+        let mut carry = 0u64;
+        for (i, limb) in self.limbs.iter_mut().enumerate() {
+            let sum = *limb as u64 + other.limbs.get(i).copied().unwrap_or(0) as u64 + carry;
+            *limb = sum as u32;
+            carry = sum >> 32;
+            if carry == 0 && i >= other.limbs.len() {
+                break;
+            }
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+}
+
+impl std::fmt::Display for BigUint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const CHUNK: u32 = 1_000_000_000;
+
+        // Base 10^9 digits, least significant first
+        let mut chunks = Vec::new();
+        let mut rest = self.clone();
+        while !rest.is_zero() {
+            let (quotient, remainder) = rest.div_rem_small(CHUNK);
+            chunks.push(remainder);
+            rest = quotient;
+        }
+
+        match chunks.split_last() {
+            None => write!(f, "0"),
+            Some((most, others)) => {
+                write!(f, "{}", most)?;
+                others.iter().rev().try_for_each(|chunk| write!(f, "{:09}", chunk))
+            }
+        }
+    }
+}
+
+/// Result
+#[derive(Debug)]
+pub struct HamiltonianReport {
+    pub directed_count: BigUint, // directed cycles, each counted once whatever city it starts at
+    pub undirected_count: Option<BigUint>, // symmetric edge set with at least 3 cities: the two directions count once
+    pub witness: Option<Vec<usize>>, // one cycle from city 0, when any exists
+    pub stats: SolverStats,
+}
+
+impl HamiltonianReport {
+    pub fn exists(&self) -> bool {
+        !self.directed_count.is_zero()
+    }
+}
+
+/// Existence and number of Hamiltonian cycles in the edge set (weights, precedences and edge
+/// constraints are ignored). Same subset DP as `TSPSolver`, with counts instead of costs:
+/// f(i, ∅) = [i → 0], f(i, S) = Σ f(j, S - {j}) over j ∈ S with i → j.
+pub struct HamiltonianCounter {
+    graph: Graph,
+}
+
+impl HamiltonianCounter {
+    pub fn new(graph: Graph) -> Result<Self> {
+        if graph.size < 2 {
+            return Err(TSPError::SolverError(
+                "Graf harus memiliki minimal 2 node".to_string()
+            ));
+        }
+        if graph.size > MAX_SIZE {
+            return Err(TSPError::SolverError(
+                format!("Graf terlalu besar (>{} node) untuk menghitung siklus Hamilton", MAX_SIZE)
+            ));
+        }
+
+        Ok(HamiltonianCounter { graph })
+    }
+
+    pub fn count(&self) -> HamiltonianReport {
+        let mut stats = SolverStats {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        };
+
+        // City 0 is the fixed start; bit k - 1 of a mask stands for city k
+        let n = self.graph.size;
+        let inner = n - 1;
+        let full = (1usize << inner) - 1;
+        let bit = |city: usize| 1usize << (city - 1);
+
+        // counts[mask][i - 1] = f(i, mask): paths from i through every city of mask, then back to 0
+        let mut counts = vec![vec![BigUint::zero(); inner]; full + 1];
+        for i in 1..n {
+            if self.graph.has_edge(i, 0) {
+                counts[0][i - 1] = BigUint::from(1);
+            }
+        }
+
+        for mask in 1..=full {
+            for i in (1..n).filter(|&i| mask & bit(i) == 0) {
+                let mut total = BigUint::zero();
+                for j in (1..n).filter(|&j| mask & bit(j) != 0 && self.graph.has_edge(i, j)) {
+                    total += &counts[mask & !bit(j)][j - 1];
+                }
+                counts[mask][i - 1] = total;
+                stats.states_computed += 1;
+            }
+        }
+        stats.max_memory_states = (full + 1) * inner;
+
+        let mut directed_count = BigUint::zero();
+        for k in (1..n).filter(|&k| self.graph.has_edge(0, k)) {
+            directed_count += &counts[full & !bit(k)][k - 1];
+        }
+
+        let witness = (!directed_count.is_zero()).then(|| {
+            let mut path = vec![0];
+            let (mut current, mut mask) = (0, full);
+            while mask != 0 {
+                let next = (1..n)
+                    .find(|&j| mask & bit(j) != 0
+                        && self.graph.has_edge(current, j)
+                        && !counts[mask & !bit(j)][j - 1].is_zero())
+                    .expect("hitungan positif menjamin ada langkah berikutnya");
+                path.push(next);
+                mask &= !bit(next);
+                current = next;
+            }
+            path.push(0);
+            path
+        });
+
+        let symmetric = (0..n).all(|i| (0..n).all(|j| self.graph.has_edge(i, j) == self.graph.has_edge(j, i)));
+        let undirected_count = (symmetric && n >= 3).then(|| directed_count.div_rem_small(2).0);
+
+        stats.solve_duration = stats.start_time.map(|t| t.elapsed());
+
+        HamiltonianReport { directed_count, undirected_count, witness, stats }
+    }
+}
+
+impl std::fmt::Display for HamiltonianReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "=== HAMILTONIAN CYCLES ===")?;
+        writeln!(f, "Exists: {}", if self.exists() { "Yes" } else { "No" })?;
+        writeln!(f, "Directed Cycles: {}", self.directed_count)?;
+        if let Some(count) = &self.undirected_count {
+            writeln!(f, "Undirected Cycles: {}", count)?;
+        }
+        if let Some(witness) = &self.witness {
+            writeln!(f, "Witness: {}",
+                    witness.iter()
+                        .map(|&i| (i + 1).to_string())
+                        .collect::<Vec<_>>()
+                        .join(" -> "))?;
+        }
+        writeln!(f, "{}", self.stats)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn complete_graph(n: usize) -> Graph {
+        Graph::new((0..n).map(|i| (0..n).map(|j| if i == j { 0 } else { 1 }).collect()).collect()).unwrap()
+    }
+
+    #[test]
+    fn test_big_uint() {
+        let mut value = BigUint::from(u64::MAX);
+        value += &BigUint::from(1);
+        assert_eq!(value.to_string(), "18446744073709551616");
+
+        let mut sum = value.clone();
+        for _ in 0..999 {
+            sum += &value;
+        }
+        assert_eq!(sum.to_string(), "18446744073709551616000");
+        assert_eq!(sum.div_rem_small(1000), (value, 0));
+        assert_eq!(BigUint::zero().to_string(), "0");
+    }
+
+    #[test]
+    fn test_count_complete_graphs() -> Result<()> {
+        // (n - 1)! directed cycles, half of them as undirected cycles
+        let report = HamiltonianCounter::new(complete_graph(4))?.count();
+        assert_eq!(report.directed_count, BigUint::from(6));
+        assert_eq!(report.undirected_count, Some(BigUint::from(3)));
+
+        let graph = complete_graph(13);
+        let report = HamiltonianCounter::new(graph.clone())?.count();
+        assert_eq!(report.directed_count.to_string(), "479001600");
+        let witness = report.witness.unwrap();
+        assert!(graph.is_valid_tour(&witness));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_directed_and_missing_cycles() -> Result<()> {
+        // One-way ring 0 -> 1 -> 2 -> 3 -> 0 plus the chord 0 -> 2
+        let mut matrix = vec![vec![None; 4]; 4];
+        for (from, to) in [(0, 1), (1, 2), (2, 3), (3, 0), (0, 2)] {
+            matrix[from][to] = Some(1);
+        }
+        let report = HamiltonianCounter::new(Graph::from_optional_matrix(matrix.clone()).unwrap())?.count();
+        assert_eq!(report.directed_count, BigUint::from(1));
+        assert_eq!(report.undirected_count, None);
+        assert_eq!(report.witness, Some(vec![0, 1, 2, 3, 0]));
+
+        matrix[2][3] = None;
+        let report = HamiltonianCounter::new(Graph::from_optional_matrix(matrix).unwrap())?.count();
+        assert!(!report.exists());
+        assert_eq!(report.witness, None);
+
+        Ok(())
+    }
+}
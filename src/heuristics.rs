@@ -0,0 +1,426 @@
+/// Libraries
+use crate::feasibility::FeasibilityReport;
+use crate::tsp_solver::{Objective, RouteMode, RouteScore, SolverStats, TSPSolution};
+use crate::{Graph, Result, TSPError};
+use std::time::Instant;
+
+/// Upper bound on local search passes (each pass restarts after an improving move)
+const MAX_PASSES: usize = 10_000;
+
+/// Edge constraint violations, objective key and score of a route
+type RouteKey = (usize, (i32, i32), RouteScore);
+
+/// Nearest neighbour construction followed by local search (2-opt reversal and node relocation).
+/// No size limit, but no optimality guarantee either.
+pub struct HeuristicSolver {
+    graph: Graph,
+    mode: RouteMode,
+    objective: Objective,
+}
+
+impl HeuristicSolver {
+    pub fn new(graph: Graph) -> Result<Self> {
+        graph.validate_for_tsp()?;
+
+        if graph.size < 2 {
+            return Err(TSPError::SolverError(
+                "Graf harus memiliki minimal 2 node".to_string()
+            ));
+        }
+
+        Ok(HeuristicSolver {
+            graph,
+            mode: RouteMode::default(),
+            objective: Objective::default(),
+        })
+    }
+
+    pub fn with_mode(mut self, mode: RouteMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn with_objective(mut self, objective: Objective) -> Self {
+        self.objective = objective;
+        self
+    }
+
+    pub fn solve(&self) -> Result<TSPSolution> {
+        self.mode.validate(self.graph.size)?;
+        FeasibilityReport::analyze(&self.graph, &self.mode).into_result()?;
+
+        let mut stats = SolverStats {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        };
+
+        // Free start: every city is tried as the first one
+        let starts: Vec<usize> = match self.mode.start() {
+            Some(start) => vec![start],
+            None => (0..self.graph.size).collect(),
+        };
+
+        let mut best: Option<(RouteKey, Vec<usize>)> = None;
+        for start in starts {
+            let Some(route) = self.nearest_neighbour(start) else {
+                continue;
+            };
+            let Some((key, route)) = self.improve(route, &mut stats) else {
+                continue;
+            };
+
+            if best.as_ref().is_none_or(|(best_key, _)| key < *best_key) {
+                best = Some((key, route));
+            }
+        }
+
+        let ((violations, _, score), path) = best.ok_or_else(||
+            TSPError::SolverError("Heuristik tidak menemukan rute yang valid".to_string())
+        )?;
+        if violations > 0 {
+            return Err(TSPError::SolverError(
+                "Heuristik tidak menemukan rute yang memenuhi batasan edge wajib/terlarang".to_string()
+            ));
+        }
+
+        let verification = self.graph.verify_route(&path, &self.mode, Some(score.cost));
+        stats.solve_duration = stats.start_time.map(|t| t.elapsed());
+
+        Ok(TSPSolution {
+            optimal_cost: score.cost,
+            bottleneck: score.bottleneck,
+            objective: self.objective,
+            origin: path[0],
+            optimal_path: path,
+            stats,
+            is_valid: verification.is_valid(),
+            verification,
+            optimal_tour_count: 0, // unknown: a heuristic does not prove optimality
+            mode: self.mode,
+        })
+    }
+
+    /// Greedy route: always move to the closest unvisited city (arcs allowed by the edge
+    /// constraints first), the fixed end is appended last
+    fn nearest_neighbour(&self, start: usize) -> Option<Vec<usize>> {
+        let mut visited = vec![false; self.graph.size];
+        visited[start] = true;
+        if let Some(end) = self.mode.end() {
+            visited[end] = true;
+        }
+
+        // Cities already on the route (the fixed end is only reserved, not placed)
+        let mut placed = vec![false; self.graph.size];
+        placed[start] = true;
+
+        let mut route = vec![start];
+        let mut current = start;
+        while let Some(next) = (0..self.graph.size)
+            .filter(|&j| !visited[j] && self.graph.has_edge(current, j))
+            .filter(|&j| self.graph.precedences.iter().all(|&(before, after)| after != j || placed[before]))
+            .min_by_key(|&j| (!self.graph.edge_constraints.allows(current, j), self.graph.get_distance(current, j), j))
+        {
+            visited[next] = true;
+            placed[next] = true;
+            route.push(next);
+            current = next;
+        }
+
+        if visited.iter().any(|&v| !v) {
+            return None;
+        }
+        route.extend(self.mode.end());
+        Some(route)
+    }
+
+    /// Key of a route, None when it uses a missing edge or breaks a precedence
+    /// (broken edge constraints are counted so local search can repair them)
+    fn evaluate(&self, route: &[usize]) -> Option<RouteKey> {
+        if !self.graph.precedence_violations(route).is_empty() {
+            return None;
+        }
+
+        let score = RouteScore::of_path(&self.graph, route)?;
+        let constraints = &self.graph.edge_constraints;
+        let violations = constraints.missing_required(route).len() + constraints.forbidden_used(route).len();
+        Some((violations, self.objective.key(score), score))
+    }
+
+    /// First-improvement local search over the positions between the fixed endpoints
+    fn improve(&self, route: Vec<usize>, stats: &mut SolverStats) -> Option<(RouteKey, Vec<usize>)> {
+        let lo = if self.mode.start().is_some() { 1 } else { 0 };
+        let hi = if self.mode.end().is_some() { route.len() - 1 } else { route.len() };
+        local_search(route, lo..hi, |candidate| self.evaluate(candidate), stats)
+    }
+}
+
+/// First-improvement local search (2-opt reversal and node relocation) over `movable` positions.
+/// `evaluate` returns None for infeasible routes; smaller keys are better.
+pub(crate) fn local_search<K, F>(
+    mut route: Vec<usize>,
+    movable: std::ops::Range<usize>,
+    evaluate: F,
+    stats: &mut SolverStats,
+) -> Option<(K, Vec<usize>)>
+where
+    K: PartialOrd + Copy,
+    F: Fn(&[usize]) -> Option<K>,
+{
+    let mut best_key = evaluate(&route)?;
+    let (lo, hi) = (movable.start, movable.end);
+
+    for _ in 0..MAX_PASSES {
+        let mut improved = false;
+
+        // 2-opt: reverse route[i..=j]
+        'two_opt: for i in lo..hi {
+            for j in i + 1..hi {
+                let mut candidate = route.clone();
+                candidate[i..=j].reverse();
+                stats.states_computed += 1;
+
+                if let Some(key) = evaluate(&candidate).filter(|&key| key < best_key) {
+                    route = candidate;
+                    best_key = key;
+                    improved = true;
+                    break 'two_opt;
+                }
+            }
+        }
+
+        // Relocation: move the city at i to position j
+        'relocate: for i in lo..hi {
+            for j in lo..hi {
+                if i == j {
+                    continue;
+                }
+
+                let mut candidate = route.clone();
+                let node = candidate.remove(i);
+                candidate.insert(j, node);
+                stats.states_computed += 1;
+
+                if let Some(key) = evaluate(&candidate).filter(|&key| key < best_key) {
+                    route = candidate;
+                    best_key = key;
+                    improved = true;
+                    break 'relocate;
+                }
+            }
+        }
+
+        if !improved {
+            break;
+        }
+    }
+
+    Some((best_key, route))
+}
+
+/// Held-Karp over (visited set, last city) for a tour from `origin`, for solvers whose edge costs
+/// are not the plain matrix. `extend(key, from, to)` is the key after taking from -> to (None when
+/// it cannot be taken); usable edges and precedences are checked here. Exact as long as a smaller
+/// key at a state is never worse later on.
+pub(crate) fn held_karp_tour<K, F>(
+    graph: &Graph,
+    origin: usize,
+    initial: K,
+    extend: F,
+    stats: &mut SolverStats,
+) -> Option<(K, Vec<usize>)>
+where
+    K: PartialOrd + Copy,
+    F: Fn(K, usize, usize) -> Option<K>,
+{
+    let n = graph.size;
+    let full = (1usize << n) - 1;
+    let origin_bit = 1usize << origin;
+
+    // (key, previous city) per (visited mask, last city)
+    let mut dp: Vec<Vec<Option<(K, usize)>>> = vec![vec![None; n]; full + 1];
+    dp[origin_bit][origin] = Some((initial, origin));
+
+    for mask in (origin_bit..=full).filter(|mask| mask & origin_bit != 0) {
+        for last in 0..n {
+            let Some((key, _)) = dp[mask][last] else {
+                continue;
+            };
+            for j in (0..n).filter(|&j| mask & (1 << j) == 0 && graph.can_travel(last, j)) {
+                if graph.predecessors_mask(j) & !(mask as u64) != 0 {
+                    continue;
+                }
+                let Some(next) = extend(key, last, j) else {
+                    continue;
+                };
+                let entry = &mut dp[mask | (1 << j)][j];
+                if entry.is_none_or(|(current, _)| next < current) {
+                    *entry = Some((next, last));
+                }
+                stats.states_computed += 1;
+            }
+        }
+    }
+    stats.max_memory_states = stats.max_memory_states.max((full + 1) * n);
+
+    let mut best: Option<(K, usize)> = None;
+    for last in (0..n).filter(|&last| last != origin && graph.can_travel(last, origin)) {
+        let Some(key) = dp[full][last].and_then(|(key, _)| extend(key, last, origin)) else {
+            continue;
+        };
+        if best.is_none_or(|(current, _)| key < current) {
+            best = Some((key, last));
+        }
+    }
+    let (key, mut last) = best?;
+
+    let mut path = vec![origin];
+    let mut mask = full;
+    while last != origin {
+        path.push(last);
+        let (_, previous) = dp[mask][last]?;
+        mask &= !(1 << last);
+        last = previous;
+    }
+    path.push(origin);
+    path.reverse();
+    Some((key, path))
+}
+
+/// Every feasible tour from `origin` (usable edges, precedences and edge constraints), in
+/// lexicographic order
+pub(crate) fn feasible_tours(graph: &Graph, origin: usize) -> Vec<Vec<usize>> {
+    fn extend(graph: &Graph, origin: usize, path: &mut Vec<usize>, mask: u64, tours: &mut Vec<Vec<usize>>) {
+        let n = graph.size;
+        let last = path[path.len() - 1];
+        if path.len() == n {
+            if graph.can_travel(last, origin) {
+                let mut tour = path.clone();
+                tour.push(origin);
+                if graph.verify_route(&tour, &RouteMode::Tour { origin }, None).is_valid() {
+                    tours.push(tour);
+                }
+            }
+            return;
+        }
+        for j in (0..n).filter(|&j| mask & (1 << j) == 0 && graph.can_travel(last, j)) {
+            if graph.predecessors_mask(j) & !mask != 0 {
+                continue;
+            }
+            path.push(j);
+            extend(graph, origin, path, mask | (1 << j), tours);
+            path.pop();
+        }
+    }
+
+    let mut tours = Vec::new();
+    extend(graph, origin, &mut vec![origin], 1u64 << origin, &mut tours);
+    tours
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TSPSolver;
+
+    fn document_graph() -> Graph {
+        let matrix = vec![
+            vec![0, 10, 15, 20],
+            vec![5,  0,  9, 10],
+            vec![6, 13,  0, 12],
+            vec![8,  8,  9,  0],
+        ];
+        Graph::new(matrix).unwrap()
+    }
+
+    #[test]
+    fn test_heuristic_every_mode() -> Result<()> {
+        let graph = document_graph();
+        let modes = [
+            RouteMode::Tour { origin: 0 },
+            RouteMode::Tour { origin: 3 },
+            RouteMode::OpenPath { start: None },
+            RouteMode::OpenPath { start: Some(2) },
+            RouteMode::PathBetween { start: 0, end: 3 },
+        ];
+
+        for mode in modes {
+            let heuristic = HeuristicSolver::new(graph.clone())?.with_mode(mode).solve()?;
+            let exact = TSPSolver::new(graph.clone())?.with_mode(mode).solve()?;
+
+            assert!(heuristic.is_valid, "{:?}: {:?}", mode, heuristic.verification.issues);
+            assert!(heuristic.optimal_cost >= exact.optimal_cost);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_heuristic_path_between_endpoints() -> Result<()> {
+        let solution = HeuristicSolver::new(document_graph())?
+            .with_mode(RouteMode::PathBetween { start: 2, end: 1 })
+            .solve()?;
+
+        assert_eq!(solution.optimal_path.first(), Some(&2));
+        assert_eq!(solution.optimal_path.last(), Some(&1));
+        assert_eq!(solution.optimal_path.len(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_heuristic_respects_precedence() -> Result<()> {
+        let mut graph = document_graph();
+        graph.add_precedence(2, 1)?;
+        graph.add_precedence(3, 2)?;
+
+        let solution = HeuristicSolver::new(graph)?.solve()?;
+        assert!(solution.is_valid, "{:?}", solution.verification.issues);
+        assert_eq!(solution.optimal_path, vec![0, 3, 2, 1, 0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_heuristic_objectives() -> Result<()> {
+        for objective in [Objective::Bottleneck, Objective::MaxCost, Objective::CostThenBottleneck] {
+            let heuristic = HeuristicSolver::new(document_graph())?.with_objective(objective).solve()?;
+            let exact = TSPSolver::new(document_graph())?.with_objective(objective).solve()?;
+
+            assert!(heuristic.is_valid, "{:?}: {:?}", objective, heuristic.verification.issues);
+            assert_eq!(heuristic.objective, objective);
+            let key = |s: &TSPSolution| objective.key(RouteScore { cost: s.optimal_cost, bottleneck: s.bottleneck });
+            assert!(key(&heuristic) >= key(&exact));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_heuristic_edge_constraints() -> Result<()> {
+        let mut graph = document_graph();
+        graph.forbid_edge(0, 1)?;
+        graph.require_edge(2, 1)?;
+
+        let solution = HeuristicSolver::new(graph)?.solve()?;
+        assert!(solution.is_valid, "{:?}", solution.verification.issues);
+        assert_eq!(solution.optimal_path, vec![0, 2, 1, 3, 0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_held_karp_tour_and_feasible_tours() -> Result<()> {
+        let mut graph = document_graph();
+        let mut stats = SolverStats::default();
+        let weight = |cost: i32, i: usize, j: usize| Some(cost + graph.get_distance(i, j));
+        assert_eq!(held_karp_tour(&graph, 0, 0, weight, &mut stats), Some((35, vec![0, 1, 3, 2, 0])));
+        assert_eq!(feasible_tours(&graph, 0).len(), 6);
+
+        graph.add_precedence(2, 1)?;
+        let weight = |cost: i32, i: usize, j: usize| Some(cost + graph.get_distance(i, j));
+        assert_eq!(held_karp_tour(&graph, 0, 0, weight, &mut stats), Some((40, vec![0, 2, 3, 1, 0])));
+        assert_eq!(feasible_tours(&graph, 0), vec![vec![0, 2, 1, 3, 0], vec![0, 2, 3, 1, 0], vec![0, 3, 2, 1, 0]]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,185 @@
+/// Libraries
+use crate::tsp_solver::{EdgeConstraints, Objective, RouteMode, RouteScore};
+use crate::{Graph, Result, TSPError, TSPSolver};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// k-best tours (Lawler partitioning on top of the exact Held-Karp solver)
+pub struct KBestSolver {
+    graph: Graph,
+    mode: RouteMode,
+    objective: Objective,
+}
+
+/// One ranked tour (rank 1 is the optimum)
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankedTour {
+    pub rank: usize,
+    pub cost: i32,
+    pub bottleneck: i32,
+    pub path: Vec<usize>,
+    pub gap_from_optimal: i32, // how much worse than rank 1 in the objective's main measure
+}
+
+/// Subproblem of the partition: its best tour plus the constraints that produced it
+struct Candidate {
+    key: (i32, i32),
+    score: RouteScore,
+    path: Vec<usize>,
+    constraints: EdgeConstraints,
+}
+
+impl KBestSolver {
+    pub fn new(graph: Graph) -> Result<Self> {
+        // Fail early with the same errors as the exact solver
+        TSPSolver::new(graph.clone())?;
+        Ok(KBestSolver {
+            graph,
+            mode: RouteMode::default(),
+            objective: Objective::default(),
+        })
+    }
+
+    pub fn with_mode(mut self, mode: RouteMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn with_objective(mut self, objective: Objective) -> Self {
+        self.objective = objective;
+        self
+    }
+
+    /// The k best distinct tours, ranked by the objective (ties by path order)
+    pub fn solve(&self, k: usize) -> Result<Vec<RankedTour>> {
+        if k == 0 {
+            return Err(TSPError::SolverError("k harus minimal 1".to_string()));
+        }
+
+        let mut candidates: Vec<Candidate> = Vec::new();
+        let mut queue = BinaryHeap::new();
+
+        if let Some(candidate) = self.solve_subproblem(EdgeConstraints::default())? {
+            queue.push(Reverse((candidate.key, candidate.path.clone(), candidates.len())));
+            candidates.push(candidate);
+        }
+
+        let mut ranked: Vec<RankedTour> = Vec::new();
+        while ranked.len() < k {
+            let Some(Reverse((key, path, index))) = queue.pop() else {
+                break;
+            };
+
+            // The root subproblem is always ranked first
+            let score = candidates[index].score;
+            let optimal_key = candidates[0].key;
+            ranked.push(RankedTour {
+                rank: ranked.len() + 1,
+                cost: score.cost,
+                bottleneck: score.bottleneck,
+                gap_from_optimal: key.0 - optimal_key.0,
+                path: path.clone(),
+            });
+
+            if ranked.len() == k {
+                break;
+            }
+
+            // Partition: keep the first l-1 free edges of the tour, forbid the l-th
+            let parent = candidates[index].constraints.clone();
+            let free_edges: Vec<(usize, usize)> = path
+                .windows(2)
+                .map(|w| (w[0], w[1]))
+                .filter(|edge| !parent.required.contains(edge))
+                .collect();
+
+            for (l, &edge) in free_edges.iter().enumerate() {
+                let mut constraints = parent.clone();
+                constraints.required.extend_from_slice(&free_edges[..l]);
+                constraints.forbidden.insert(edge);
+
+                if let Some(candidate) = self.solve_subproblem(constraints)? {
+                    queue.push(Reverse((candidate.key, candidate.path.clone(), candidates.len())));
+                    candidates.push(candidate);
+                }
+            }
+        }
+
+        Ok(ranked)
+    }
+
+    fn solve_subproblem(&self, constraints: EdgeConstraints) -> Result<Option<Candidate>> {
+        let mut solver = TSPSolver::new(self.graph.clone())?
+            .with_mode(self.mode)
+            .with_objective(self.objective)
+            .with_edge_constraints(constraints.clone());
+
+        let Some((_, path)) = solver.solve_best_tour()? else {
+            return Ok(None);
+        };
+        let score = RouteScore::of_path(&self.graph, &path).ok_or_else(|| TSPError::SolverError(
+            "Tour k-best memakai edge yang tidak ada".to_string()
+        ))?;
+
+        Ok(Some(Candidate {
+            key: self.objective.key(score),
+            score,
+            path,
+            constraints,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_k_best_document_example() -> Result<()> {
+        let matrix = vec![
+            vec![0, 10, 15, 20],
+            vec![5,  0,  9, 10],
+            vec![6, 13,  0, 12],
+            vec![8,  8,  9,  0],
+        ];
+
+        let graph = Graph::new(matrix)?;
+        let ranked = KBestSolver::new(graph.clone())?.solve(6)?;
+
+        // 4 cities have exactly 3! = 6 directed tours from node 0
+        assert_eq!(ranked.len(), 6);
+        assert_eq!(ranked[0].cost, 35);
+        assert_eq!(ranked[0].gap_from_optimal, 0);
+
+        for window in ranked.windows(2) {
+            assert!(window[0].cost <= window[1].cost);
+            assert_ne!(window[0].path, window[1].path);
+        }
+
+        for tour in &ranked {
+            assert!(graph.is_valid_tour(&tour.path));
+            assert_eq!(graph.calculate_path_cost(&tour.path)?, tour.cost);
+            assert_eq!(tour.gap_from_optimal, tour.cost - 35);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_k_best_bottleneck_objective() -> Result<()> {
+        let graph = Graph::new(vec![
+            vec![0, 10, 15, 20],
+            vec![5,  0,  9, 10],
+            vec![6, 13,  0, 12],
+            vec![8,  8,  9,  0],
+        ])?;
+        let ranked = KBestSolver::new(graph)?.with_objective(Objective::Bottleneck).solve(4)?;
+
+        // By total the 4th tour is 0-3-1-2-0 (43, bottleneck 20); by bottleneck both 15 tours come first
+        let bottlenecks: Vec<i32> = ranked.iter().map(|tour| tour.bottleneck).collect();
+        assert_eq!(bottlenecks, vec![10, 12, 15, 15]);
+        assert!(ranked.iter().all(|tour| tour.gap_from_optimal == tour.bottleneck - 10));
+
+        Ok(())
+    }
+}
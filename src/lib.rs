@@ -2,11 +2,29 @@ pub mod graph;
 pub mod tsp_solver;
 pub mod visualization;
 pub mod config;
+pub mod heuristics;
+pub mod k_best;
+pub mod sensitivity;
+pub mod time_windows;
+pub mod pickup_delivery;
+pub mod multi_tsp;
+pub mod cvrp;
+pub mod prize_collecting;
+pub mod gtsp;
+pub mod feasibility;
+pub mod metric_closure;
+pub mod pareto;
+pub mod time_dependent;
+pub mod robust;
+pub mod stochastic;
+pub mod hamiltonian;
 
 pub use graph::Graph;
 pub use tsp_solver::TSPSolver;
 pub use visualization::Visualizer;
 pub use config::ConfigReader;
+pub use heuristics::HeuristicSolver;
+pub use k_best::KBestSolver;
 
 #[derive(Debug)]
 pub enum TSPError {
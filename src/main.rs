@@ -2,7 +2,20 @@ use clap::{Parser, Subcommand};
 use colored::*;
 use std::path::PathBuf;
 
-use tsp_solver::{ConfigReader, Result, TSPSolver, Visualizer};
+use tsp_solver::cvrp::CVRPSolver;
+use tsp_solver::gtsp::GTSPSolver;
+use tsp_solver::hamiltonian::HamiltonianCounter;
+use tsp_solver::metric_closure::MetricClosure;
+use tsp_solver::multi_tsp::{MultiObjective, MultiTSPSolver};
+use tsp_solver::pareto::ParetoSolver;
+use tsp_solver::pickup_delivery::PickupDeliverySolver;
+use tsp_solver::prize_collecting::{SelectiveMode, SelectiveTourSolver};
+use tsp_solver::robust::{RobustObjective, RobustSolver};
+use tsp_solver::stochastic::{StochasticObjective, StochasticSolver};
+use tsp_solver::time_dependent::TimeDependentSolver;
+use tsp_solver::time_windows::TimeWindowSolver;
+use tsp_solver::tsp_solver::{Objective, RouteMode};
+use tsp_solver::{ConfigReader, HeuristicSolver, KBestSolver, Result, TSPError, TSPSolver, Visualizer};
 
 #[derive(Parser)]
 #[command(name = "tsp_solver")]
@@ -21,6 +34,237 @@ enum Commands {
         
         #[arg(short, long)]
         verbose: bool,
+
+        /// List up to N co-optimal tours
+        #[arg(long, value_name = "N")]
+        all_optimal: Option<usize>,
+
+        /// Rank the K cheapest tours
+        #[arg(long, value_name = "K")]
+        k_best: Option<usize>,
+
+        /// Per-edge sensitivity analysis of the optimal tour
+        #[arg(long)]
+        sensitivity: bool,
+
+        /// Origin city of the tour, by name or number (default: first city)
+        #[arg(long, value_name = "CITY", conflicts_with_all = ["open", "from"])]
+        origin: Option<String>,
+
+        /// Open Hamiltonian path (no return to the start)
+        #[arg(long)]
+        open: bool,
+
+        /// Fixed start city of the open path (free when omitted)
+        #[arg(long, value_name = "CITY", requires = "open")]
+        start: Option<String>,
+
+        /// Start city of a path with a fixed destination
+        #[arg(long, value_name = "CITY", requires = "to", conflicts_with = "open")]
+        from: Option<String>,
+
+        /// Destination city of a path with a fixed start
+        #[arg(long, value_name = "CITY", requires = "from")]
+        to: Option<String>,
+
+        /// Use the nearest neighbour + local search heuristic instead of the exact DP
+        #[arg(long, conflicts_with_all = ["all_optimal", "k_best", "sensitivity"])]
+        heuristic: bool,
+
+        /// Objective: cost (min length), bottleneck (min longest edge), max (max length)
+        /// or lex (min length, then min longest edge)
+        #[arg(long, default_value = "cost")]
+        objective: String,
+
+        /// Solve on the shortest-path closure, passing through cities without a direct edge
+        #[arg(long, conflicts_with_all = ["all_optimal", "k_best", "sensitivity"])]
+        closure: bool,
+    },
+
+    /// Tour that serves every city within its time window
+    TimeWindows {
+        #[arg(short, long)]
+        file: PathBuf,
+
+        /// Origin city (depot), by name or number (default: first city)
+        #[arg(long, value_name = "CITY")]
+        origin: Option<String>,
+
+        /// Use the deadline-ordered local search heuristic instead of the exact DP
+        #[arg(long)]
+        heuristic: bool,
+    },
+
+    /// Travel times follow a schedule of matrices (SCHEDULE + MATRIX_<NAME> in the file)
+    TimeDependent {
+        #[arg(short, long)]
+        file: PathBuf,
+
+        /// Origin city, by name or number (default: first city)
+        #[arg(long, value_name = "CITY")]
+        origin: Option<String>,
+
+        /// Departure time from the origin
+        #[arg(long, default_value = "0")]
+        start_time: i32,
+
+        /// Use nearest neighbour + local search instead of the exact DP
+        #[arg(long)]
+        heuristic: bool,
+    },
+
+    /// Tour for uncertain edge costs (`low..high` entries in MATRIX)
+    Robust {
+        #[arg(short, long)]
+        file: PathBuf,
+
+        /// Objective: regret (min-max regret) or worst (worst-case cost)
+        #[arg(long, default_value = "regret")]
+        objective: String,
+
+        /// Origin city, by name or number (default: first city)
+        #[arg(long, value_name = "CITY")]
+        origin: Option<String>,
+
+        /// Solve the midpoint (or upper-bound) scenario instead of the exact search
+        #[arg(long)]
+        heuristic: bool,
+    },
+
+    /// Tour for random edge costs (DISTRIBUTIONS in the file), evaluated by Monte Carlo
+    Stochastic {
+        #[arg(short, long)]
+        file: PathBuf,
+
+        /// Minimize this quantile of the tour cost (e.g. 0.9) instead of the expected cost
+        #[arg(long, value_name = "Q")]
+        quantile: Option<f64>,
+
+        /// Number of Monte Carlo samples
+        #[arg(long, default_value = "1000")]
+        samples: usize,
+
+        /// Seed of the random number generator
+        #[arg(long, default_value = "42")]
+        seed: u64,
+
+        /// Origin city, by name or number (default: first city)
+        #[arg(long, value_name = "CITY")]
+        origin: Option<String>,
+
+        /// Start from the expected-cost tour and improve it by local search
+        #[arg(long)]
+        heuristic: bool,
+    },
+
+    /// Decide whether a Hamiltonian cycle exists in the edge set and count them
+    Hamiltonian {
+        #[arg(short, long)]
+        file: PathBuf,
+    },
+
+    /// Tour serving pickup-and-delivery requests within the vehicle capacity
+    PickupDelivery {
+        #[arg(short, long)]
+        file: PathBuf,
+
+        /// Origin city (depot), by name or number (default: first city)
+        #[arg(long, value_name = "CITY")]
+        origin: Option<String>,
+
+        /// Use the greedy + local search heuristic instead of the exact DP
+        #[arg(long)]
+        heuristic: bool,
+    },
+
+    /// Several salesmen leaving from one depot
+    Multi {
+        #[arg(short, long)]
+        file: PathBuf,
+
+        /// Number of salesmen
+        #[arg(short, long, default_value = "2")]
+        salesmen: usize,
+
+        /// Objective: sum (total distance) or max (longest tour)
+        #[arg(long, default_value = "sum")]
+        objective: String,
+
+        /// Minimum cities per salesman (0 allows idle salesmen)
+        #[arg(long, default_value = "1")]
+        min_stops: usize,
+
+        /// Maximum cities per salesman
+        #[arg(long)]
+        max_stops: Option<usize>,
+
+        /// Depot city, by name or number (default: first city)
+        #[arg(long, value_name = "CITY")]
+        depot: Option<String>,
+
+        /// Use the split + local search heuristic instead of the exact DP
+        #[arg(long)]
+        heuristic: bool,
+    },
+
+    /// Capacitated vehicle routing from one depot (DEMANDS + CAPACITY in the file)
+    Cvrp {
+        #[arg(short, long)]
+        file: PathBuf,
+
+        /// Depot city, by name or number (default: first city)
+        #[arg(long, value_name = "CITY")]
+        depot: Option<String>,
+    },
+
+    /// Visit only the cities worth it (PRIZES in the file)
+    Selective {
+        #[arg(short, long)]
+        file: PathBuf,
+
+        /// Orienteering distance budget (without it: prize-collecting TSP)
+        #[arg(long)]
+        budget: Option<i32>,
+
+        /// Origin city, by name or number (default: first city)
+        #[arg(long, value_name = "CITY")]
+        origin: Option<String>,
+
+        /// Use the add/drop + local search heuristic instead of the exact DP
+        #[arg(long)]
+        heuristic: bool,
+    },
+
+    /// Visit exactly one city of each cluster (CLUSTERS in the file)
+    Clusters {
+        #[arg(short, long)]
+        file: PathBuf,
+
+        /// Use the nearest neighbour + local search heuristic instead of the exact DP
+        #[arg(long)]
+        heuristic: bool,
+    },
+
+    /// Non-dominated tours over several weight matrices (MATRIX plus MATRIX_<NAME> sections)
+    Pareto {
+        #[arg(short, long)]
+        file: PathBuf,
+
+        /// Comma-separated criteria (default: every matrix in the file)
+        #[arg(long, value_name = "NAMES")]
+        criteria: Option<String>,
+
+        /// Origin city, by name or number (default: first city)
+        #[arg(long, value_name = "CITY")]
+        origin: Option<String>,
+
+        /// Approximate the front with weighted-sum local search instead of the exact DP
+        #[arg(long)]
+        heuristic: bool,
+
+        /// Write the front to a JSON file
+        #[arg(long, value_name = "FILE")]
+        export: Option<PathBuf>,
     },
 
     Sample {
@@ -48,8 +292,48 @@ fn main() -> Result<()> {
     print_header();
 
     match cli.command {
-        Commands::Solve { file, verbose } => {
-            solve_from_file(file, verbose)
+        Commands::Solve {
+            file, verbose, all_optimal, k_best, sensitivity, origin, open, start, from, to, heuristic, objective, closure,
+        } => {
+            let options = SolveOptions {
+                verbose, all_optimal, k_best, sensitivity, origin, open, start, from, to, heuristic, objective, closure,
+            };
+            solve_from_file(file, options)
+        }
+        Commands::TimeWindows { file, origin, heuristic } => {
+            solve_time_windows(file, origin, heuristic)
+        }
+        Commands::TimeDependent { file, origin, start_time, heuristic } => {
+            solve_time_dependent(file, origin, start_time, heuristic)
+        }
+        Commands::Robust { file, objective, origin, heuristic } => {
+            solve_robust(file, objective, origin, heuristic)
+        }
+        Commands::Stochastic { file, quantile, samples, seed, origin, heuristic } => {
+            let options = StochasticOptions { quantile, samples, seed, origin, heuristic };
+            solve_stochastic(file, options)
+        }
+        Commands::Hamiltonian { file } => {
+            count_hamiltonian(file)
+        }
+        Commands::PickupDelivery { file, origin, heuristic } => {
+            solve_pickup_delivery(file, origin, heuristic)
+        }
+        Commands::Multi { file, salesmen, objective, min_stops, max_stops, depot, heuristic } => {
+            let options = MultiOptions { salesmen, objective, min_stops, max_stops, depot, heuristic };
+            solve_multi(file, options)
+        }
+        Commands::Cvrp { file, depot } => {
+            solve_cvrp(file, depot)
+        }
+        Commands::Selective { file, budget, origin, heuristic } => {
+            solve_selective(file, budget, origin, heuristic)
+        }
+        Commands::Clusters { file, heuristic } => {
+            solve_clusters(file, heuristic)
+        }
+        Commands::Pareto { file, criteria, origin, heuristic, export } => {
+            solve_pareto(file, criteria, origin, heuristic, export)
         }
         Commands::Sample { output, kind } => {
             create_sample_file(output, kind)
@@ -72,7 +356,34 @@ fn print_header() {
     println!();
 }
 
-fn solve_from_file(file_path: PathBuf, verbose: bool) -> Result<()> {
+struct SolveOptions {
+    verbose: bool,
+    all_optimal: Option<usize>,
+    k_best: Option<usize>,
+    sensitivity: bool,
+    origin: Option<String>,
+    open: bool,
+    start: Option<String>,
+    from: Option<String>,
+    to: Option<String>,
+    heuristic: bool,
+    objective: String,
+    closure: bool,
+}
+
+fn solve_from_file(file_path: PathBuf, options: SolveOptions) -> Result<()> {
+    let objective = match options.objective.as_str() {
+        "cost" => Objective::MinCost,
+        "bottleneck" => Objective::Bottleneck,
+        "max" => Objective::MaxCost,
+        "lex" => Objective::CostThenBottleneck,
+        _ => {
+            println!("{} Objektif tidak dikenal: {}", "[ERROR]".bright_red(), options.objective);
+            println!("Gunakan: cost, bottleneck, max, atau lex");
+            return Ok(());
+        }
+    };
+
     println!("{} {:?}", "Membaca file:".bright_green(), file_path);
     
     let graph = ConfigReader::read_from_file(&file_path)?;
@@ -83,9 +394,62 @@ fn solve_from_file(file_path: PathBuf, verbose: bool) -> Result<()> {
     graph.visualize_ascii();
     
     Visualizer::display_complexity_info(graph.size);
+
+    let mode = if let (Some(from), Some(to)) = (&options.from, &options.to) {
+        RouteMode::PathBetween {
+            start: graph.find_node(from)?,
+            end: graph.find_node(to)?,
+        }
+    } else if options.open {
+        let start = options.start.as_deref().map(|name| graph.find_node(name)).transpose()?;
+        RouteMode::OpenPath { start }
+    } else {
+        let origin = options.origin.as_deref().map(|name| graph.find_node(name)).transpose()?;
+        RouteMode::Tour { origin: origin.unwrap_or(0) }
+    };
+
+    if options.closure {
+        println!("{}", "[START] Menghitung metric closure (Floyd-Warshall)...".bright_yellow());
+        let closure = MetricClosure::new(&graph)?;
+        let solution = if options.heuristic {
+            HeuristicSolver::new(closure.graph().clone())?
+                .with_mode(mode)
+                .with_objective(objective)
+                .solve()?
+        } else {
+            TSPSolver::new(closure.graph().clone())?
+                .with_verbose(options.verbose)
+                .with_mode(mode)
+                .with_objective(objective)
+                .solve()?
+        };
+
+        let route = closure.expand(solution);
+        println!("{}", "[SUCCESS] Solusi ditemukan!".bright_green());
+        Visualizer::display_closure_solution(&graph, &route);
+        Visualizer::display_graph_with_tour(&graph, &route.expanded_path());
+        return Ok(());
+    }
+
+    if options.heuristic {
+        println!("{}", "[START] Memulai heuristik (nearest neighbour + local search)...".bright_yellow());
+        let solution = HeuristicSolver::new(graph.clone())?
+            .with_mode(mode)
+            .with_objective(objective)
+            .solve()?;
+
+        println!("{}", "[SUCCESS] Solusi heuristik ditemukan!".bright_green());
+        Visualizer::display_solution(&graph, &solution);
+        Visualizer::display_graph_with_tour(&graph, &solution.optimal_path);
+        Visualizer::display_tour_sequence(&graph, &solution.optimal_path);
+        return Ok(());
+    }
     
     println!("{}", "[START] Memulai TSP Solver...".bright_yellow());
-    let mut solver = TSPSolver::new(graph.clone())?.with_verbose(verbose);
+    let mut solver = TSPSolver::new(graph.clone())?
+        .with_verbose(options.verbose)
+        .with_mode(mode)
+        .with_objective(objective);
     let solution = solver.solve()?;
     
     println!("{}", "[SUCCESS] Solusi ditemukan!".bright_green());
@@ -94,10 +458,359 @@ fn solve_from_file(file_path: PathBuf, verbose: bool) -> Result<()> {
     Visualizer::display_solution(&graph, &solution);
     Visualizer::display_graph_with_tour(&graph, &solution.optimal_path);
     Visualizer::display_tour_sequence(&graph, &solution.optimal_path);
+
+    if let Some(limit) = options.all_optimal {
+        let tours = solver.optimal_tours(limit)?;
+        Visualizer::display_optimal_tours(&graph, &tours, solution.optimal_tour_count);
+    }
+
+    if let Some(k) = options.k_best {
+        let ranked = KBestSolver::new(graph.clone())?
+            .with_mode(mode)
+            .with_objective(objective)
+            .solve(k)?;
+        Visualizer::display_k_best(&graph, &ranked, objective);
+    }
+
+    if options.sensitivity {
+        let report = solver.sensitivity_report()?;
+        Visualizer::display_sensitivity(&graph, &report);
+    }
     
     Ok(())
 }
 
+fn solve_time_windows(file_path: PathBuf, origin: Option<String>, heuristic: bool) -> Result<()> {
+    println!("{} {:?}", "Membaca file:".bright_green(), file_path);
+
+    let graph = ConfigReader::read_from_file(&file_path)?;
+    let timings = ConfigReader::read_time_windows(&file_path, &graph)?;
+
+    println!("{}", "[SUCCESS] File berhasil dibaca".bright_green());
+    graph.display();
+
+    let origin = origin.as_deref().map(|name| graph.find_node(name)).transpose()?;
+    let solver = TimeWindowSolver::new(graph.clone(), timings.clone())?
+        .with_origin(origin.unwrap_or(0));
+
+    println!("{}", "[START] Memulai TSP dengan jendela waktu...".bright_yellow());
+    let solution = if heuristic {
+        solver.solve_heuristic()?
+    } else {
+        solver.solve()?
+    };
+
+    println!("{}", "[SUCCESS] Solusi ditemukan!".bright_green());
+    Visualizer::display_time_window_solution(&graph, &solution, &timings);
+    Visualizer::display_tour_sequence(&graph, &solution.path);
+
+    Ok(())
+}
+
+fn solve_time_dependent(file_path: PathBuf, origin: Option<String>, start_time: i32, heuristic: bool) -> Result<()> {
+    println!("{} {:?}", "Membaca file:".bright_green(), file_path);
+
+    let graph = ConfigReader::read_from_file(&file_path)?;
+    let slots = ConfigReader::read_schedule(&file_path, &graph)?;
+
+    println!("{}", "[SUCCESS] File berhasil dibaca".bright_green());
+    graph.display();
+
+    let origin = origin.as_deref().map(|name| graph.find_node(name)).transpose()?.unwrap_or(0);
+    let solver = TimeDependentSolver::new(graph.clone(), slots)?
+        .with_origin(origin)
+        .with_start_time(start_time);
+
+    println!("{}", "[START] Memulai TSP bergantung waktu...".bright_yellow());
+    let solution = if heuristic {
+        solver.solve_heuristic()?
+    } else {
+        solver.solve()?
+    };
+
+    println!("{}", "[SUCCESS] Solusi ditemukan!".bright_green());
+    Visualizer::display_time_dependent_solution(&graph, &solution);
+
+    Ok(())
+}
+
+fn solve_robust(file_path: PathBuf, objective: String, origin: Option<String>, heuristic: bool) -> Result<()> {
+    let objective = match objective.as_str() {
+        "regret" => RobustObjective::MinMaxRegret,
+        "worst" => RobustObjective::WorstCaseCost,
+        _ => {
+            println!("{} Objektif tidak dikenal: {}", "[ERROR]".bright_red(), objective);
+            println!("Gunakan: regret atau worst");
+            return Ok(());
+        }
+    };
+
+    println!("{} {:?}", "Membaca file:".bright_green(), file_path);
+    let graph = ConfigReader::read_from_file(&file_path)?;
+    let intervals = ConfigReader::read_cost_intervals(&file_path)?;
+
+    println!("{}", "[SUCCESS] File berhasil dibaca".bright_green());
+    graph.display();
+
+    let origin = origin.as_deref().map(|name| graph.find_node(name)).transpose()?.unwrap_or(0);
+    let solver = RobustSolver::new(graph.clone(), intervals.clone())?
+        .with_objective(objective)
+        .with_origin(origin);
+
+    println!("{}", "[START] Memulai TSP robust...".bright_yellow());
+    let solution = if heuristic {
+        solver.solve_heuristic()?
+    } else {
+        solver.solve()?
+    };
+
+    println!("{}", "[SUCCESS] Solusi ditemukan!".bright_green());
+    Visualizer::display_robust_solution(&graph, &solution, &intervals);
+
+    Ok(())
+}
+
+struct StochasticOptions {
+    quantile: Option<f64>,
+    samples: usize,
+    seed: u64,
+    origin: Option<String>,
+    heuristic: bool,
+}
+
+fn solve_stochastic(file_path: PathBuf, options: StochasticOptions) -> Result<()> {
+    println!("{} {:?}", "Membaca file:".bright_green(), file_path);
+
+    let graph = ConfigReader::read_from_file(&file_path)?;
+    let distributions = ConfigReader::read_distributions(&file_path, &graph)?;
+
+    println!("{}", "[SUCCESS] File berhasil dibaca".bright_green());
+    graph.display();
+
+    let origin = options.origin.as_deref().map(|name| graph.find_node(name)).transpose()?.unwrap_or(0);
+    let objective = options.quantile.map_or(StochasticObjective::Expected, StochasticObjective::Quantile);
+    let solver = StochasticSolver::new(graph.clone(), distributions.clone())?
+        .with_objective(objective)
+        .with_origin(origin)
+        .with_samples(options.samples)
+        .with_seed(options.seed);
+
+    println!("{}", "[START] Memulai TSP stokastik...".bright_yellow());
+    let solution = if options.heuristic {
+        solver.solve_heuristic()?
+    } else {
+        solver.solve()?
+    };
+
+    println!("{}", "[SUCCESS] Solusi ditemukan!".bright_green());
+    Visualizer::display_stochastic_solution(&graph, &solution, &distributions);
+
+    Ok(())
+}
+
+fn count_hamiltonian(file_path: PathBuf) -> Result<()> {
+    println!("{} {:?}", "Membaca file:".bright_green(), file_path);
+
+    let graph = ConfigReader::read_from_file(&file_path)?;
+
+    println!("{}", "[SUCCESS] File berhasil dibaca".bright_green());
+    graph.display();
+
+    println!("{}", "[START] Menghitung siklus Hamilton...".bright_yellow());
+    let report = HamiltonianCounter::new(graph.clone())?.count();
+
+    Visualizer::display_hamiltonian_report(&graph, &report);
+
+    Ok(())
+}
+
+fn solve_pickup_delivery(file_path: PathBuf, origin: Option<String>, heuristic: bool) -> Result<()> {
+    println!("{} {:?}", "Membaca file:".bright_green(), file_path);
+
+    let graph = ConfigReader::read_from_file(&file_path)?;
+    let (requests, capacity) = ConfigReader::read_pickup_delivery(&file_path, &graph)?
+        .ok_or_else(|| TSPError::ConfigError("File tidak memiliki section PICKUP_DELIVERY".to_string()))?;
+
+    println!("{}", "[SUCCESS] File berhasil dibaca".bright_green());
+    graph.display();
+
+    let origin = origin.as_deref().map(|name| graph.find_node(name)).transpose()?;
+    let solver = PickupDeliverySolver::new(graph.clone(), requests.clone(), capacity)?
+        .with_origin(origin.unwrap_or(0));
+
+    println!("{}", "[START] Memulai pickup and delivery solver...".bright_yellow());
+    let solution = if heuristic {
+        solver.solve_heuristic()?
+    } else {
+        solver.solve()?
+    };
+
+    println!("{}", "[SUCCESS] Solusi ditemukan!".bright_green());
+    Visualizer::display_pickup_delivery_solution(&graph, &solution, &requests);
+    Visualizer::display_tour_sequence(&graph, &solution.path);
+
+    Ok(())
+}
+
+struct MultiOptions {
+    salesmen: usize,
+    objective: String,
+    min_stops: usize,
+    max_stops: Option<usize>,
+    depot: Option<String>,
+    heuristic: bool,
+}
+
+fn solve_multi(file_path: PathBuf, options: MultiOptions) -> Result<()> {
+    let objective = match options.objective.as_str() {
+        "sum" => MultiObjective::MinSum,
+        "max" => MultiObjective::MinMax,
+        _ => {
+            println!("{} Objektif tidak dikenal: {}", "[ERROR]".bright_red(), options.objective);
+            println!("Gunakan: sum atau max");
+            return Ok(());
+        }
+    };
+
+    println!("{} {:?}", "Membaca file:".bright_green(), file_path);
+    let graph = ConfigReader::read_from_file(&file_path)?;
+    println!("{}", "[SUCCESS] File berhasil dibaca".bright_green());
+    graph.display();
+
+    let depot = options.depot.as_deref().map(|name| graph.find_node(name)).transpose()?;
+    let solver = MultiTSPSolver::new(graph.clone(), options.salesmen)?
+        .with_depot(depot.unwrap_or(0))
+        .with_objective(objective)
+        .with_stop_limits(options.min_stops, options.max_stops);
+
+    println!("{}", format!("[START] Memulai mTSP dengan {} salesman...", options.salesmen).bright_yellow());
+    let solution = if options.heuristic {
+        solver.solve_heuristic()?
+    } else {
+        solver.solve()?
+    };
+
+    println!("{}", "[SUCCESS] Solusi ditemukan!".bright_green());
+    Visualizer::display_multi_tsp_solution(&graph, &solution);
+
+    Ok(())
+}
+
+fn solve_cvrp(file_path: PathBuf, depot: Option<String>) -> Result<()> {
+    println!("{} {:?}", "Membaca file:".bright_green(), file_path);
+
+    let graph = ConfigReader::read_from_file(&file_path)?;
+    let (demands, capacity) = ConfigReader::read_demands(&file_path, &graph)?
+        .ok_or_else(|| TSPError::ConfigError("File tidak memiliki section DEMANDS".to_string()))?;
+
+    println!("{}", "[SUCCESS] File berhasil dibaca".bright_green());
+    graph.display();
+
+    let depot = depot.as_deref().map(|name| graph.find_node(name)).transpose()?;
+    let solver = CVRPSolver::new(graph.clone(), demands, capacity)?.with_depot(depot.unwrap_or(0));
+
+    println!("{}", "[START] Memulai CVRP (savings + local search)...".bright_yellow());
+    let solution = solver.solve()?;
+
+    println!("{}", "[SUCCESS] Solusi ditemukan!".bright_green());
+    Visualizer::display_cvrp_solution(&graph, &solution);
+
+    Ok(())
+}
+
+fn solve_selective(file_path: PathBuf, budget: Option<i32>, origin: Option<String>, heuristic: bool) -> Result<()> {
+    println!("{} {:?}", "Membaca file:".bright_green(), file_path);
+
+    let graph = ConfigReader::read_from_file(&file_path)?;
+    let rewards = ConfigReader::read_prizes(&file_path, &graph)?;
+
+    println!("{}", "[SUCCESS] File berhasil dibaca".bright_green());
+    graph.display();
+
+    let mode = match budget {
+        Some(budget) => SelectiveMode::Orienteering { budget },
+        None => SelectiveMode::PrizeCollecting,
+    };
+    let origin = origin.as_deref().map(|name| graph.find_node(name)).transpose()?;
+    let solver = SelectiveTourSolver::new(graph.clone(), rewards.clone(), mode)?
+        .with_origin(origin.unwrap_or(0));
+
+    println!("{}", "[START] Memulai tour selektif...".bright_yellow());
+    let solution = if heuristic {
+        solver.solve_heuristic()?
+    } else {
+        solver.solve()?
+    };
+
+    println!("{}", "[SUCCESS] Solusi ditemukan!".bright_green());
+    Visualizer::display_selective_solution(&graph, &solution, &rewards);
+
+    Ok(())
+}
+
+fn solve_clusters(file_path: PathBuf, heuristic: bool) -> Result<()> {
+    println!("{} {:?}", "Membaca file:".bright_green(), file_path);
+
+    let graph = ConfigReader::read_from_file(&file_path)?;
+    let clusters = ConfigReader::read_clusters(&file_path, &graph)?;
+
+    println!("{}", "[SUCCESS] File berhasil dibaca".bright_green());
+    graph.display();
+
+    let solver = GTSPSolver::new(graph.clone(), clusters)?;
+
+    println!("{}", "[START] Memulai generalized TSP...".bright_yellow());
+    let solution = if heuristic {
+        solver.solve_heuristic()?
+    } else {
+        solver.solve()?
+    };
+
+    println!("{}", "[SUCCESS] Solusi ditemukan!".bright_green());
+    Visualizer::display_generalized_solution(&graph, &solution);
+
+    Ok(())
+}
+
+fn solve_pareto(
+    file_path: PathBuf,
+    criteria: Option<String>,
+    origin: Option<String>,
+    heuristic: bool,
+    export: Option<PathBuf>,
+) -> Result<()> {
+    println!("{} {:?}", "Membaca file:".bright_green(), file_path);
+    let graph = ConfigReader::read_from_file(&file_path)?;
+    println!("{}", "[SUCCESS] File berhasil dibaca".bright_green());
+    graph.display();
+
+    let origin = origin.as_deref().map(|name| graph.find_node(name)).transpose()?.unwrap_or(0);
+    let mut solver = ParetoSolver::new(graph.clone())?.with_origin(origin);
+    if let Some(criteria) = &criteria {
+        let names: Vec<&str> = criteria.split(',').map(|name| name.trim()).collect();
+        solver = solver.with_criteria(&names)?;
+    }
+
+    let front = if heuristic {
+        println!("{}", "[START] Memulai heuristik Pareto (skalarisasi + local search)...".bright_yellow());
+        solver.solve_heuristic()?
+    } else {
+        println!("{}", "[START] Memulai DP Pareto...".bright_yellow());
+        solver.solve()?
+    };
+
+    println!("{}", "[SUCCESS] Front Pareto ditemukan!".bright_green());
+    Visualizer::display_pareto_front(&graph, &front);
+
+    if let Some(path) = export {
+        std::fs::write(&path, front.to_json(&graph)?)
+            .map_err(|e| TSPError::ConfigError(format!("Gagal menulis file {:?}: {}", path, e)))?;
+        println!("{} {:?}", "[SUCCESS] Front Pareto disimpan ke".bright_green(), path);
+    }
+
+    Ok(())
+}
+
 fn create_sample_file(output_path: PathBuf, kind: String) -> Result<()> {
     use tsp_solver::config::SampleType;
     
@@ -0,0 +1,166 @@
+/// Libraries
+use crate::tsp_solver::TSPSolution;
+use crate::{Graph, Result, TSPError};
+
+/// One leg of a tour on the closure: the shortest road from `from` to `to` in the original graph
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClosureLeg {
+    pub from: usize,
+    pub to: usize,
+    pub via: Vec<usize>, // pass-through cities, in order
+    pub cost: i32,
+}
+
+/// Tour solved on the closure, with every leg expanded back to original edges
+#[derive(Debug)]
+pub struct ClosureSolution {
+    pub solution: TSPSolution, // visited cities only
+    pub legs: Vec<ClosureLeg>,
+}
+
+impl ClosureSolution {
+    /// The route actually driven: visited and pass-through cities
+    pub fn expanded_path(&self) -> Vec<usize> {
+        let mut path: Vec<usize> = self.legs.first().map(|leg| leg.from).into_iter().collect();
+        for leg in &self.legs {
+            path.extend(&leg.via);
+            path.push(leg.to);
+        }
+        path
+    }
+}
+
+/// All-pairs shortest paths (Floyd–Warshall): a graph where every city reachable from another
+/// gets a direct edge weighing the shortest path, so a tour may pass through cities again
+pub struct MetricClosure {
+    graph: Graph,
+    next: Vec<Vec<Option<usize>>>, // first hop of the shortest path
+}
+
+impl MetricClosure {
+    /// Forbidden arcs are never used; required arcs keep their direct weight and no pass-through
+    pub fn new(graph: &Graph) -> Result<Self> {
+        let n = graph.size;
+        let usable = |i: usize, j: usize| graph.has_edge(i, j) && graph.edge_constraints.allows(i, j);
+
+        let mut dist: Vec<Vec<Option<i32>>> = (0..n)
+            .map(|i| (0..n).map(|j| if i == j { Some(0) } else { usable(i, j).then(|| graph.get_distance(i, j)) }).collect())
+            .collect();
+        let mut next: Vec<Vec<Option<usize>>> = (0..n)
+            .map(|i| (0..n).map(|j| dist[i][j].map(|_| j)).collect())
+            .collect();
+
+        for k in 0..n {
+            for i in 0..n {
+                let Some(to_k) = dist[i][k] else {
+                    continue;
+                };
+                for j in 0..n {
+                    let Some(total) = dist[k][j].and_then(|from_k| to_k.checked_add(from_k)) else {
+                        continue;
+                    };
+                    if dist[i][j].is_none_or(|current| total < current) {
+                        dist[i][j] = Some(total);
+                        next[i][j] = next[i][k];
+                    }
+                }
+            }
+        }
+
+        if let Some(city) = (0..n).find(|&i| dist[i][i].is_some_and(|d| d < 0)) {
+            return Err(TSPError::InvalidGraph(
+                format!("Siklus negatif melalui {}, jarak terpendek tidak terdefinisi", graph.node_names[city])
+            ));
+        }
+
+        for &(from, to) in &graph.edge_constraints.required {
+            if graph.has_edge(from, to) {
+                dist[from][to] = Some(graph.get_distance(from, to));
+                next[from][to] = Some(to);
+            }
+        }
+
+        let mut closure = Graph::from_optional_matrix(dist)?;
+        closure.set_node_names(graph.node_names.clone())?;
+        closure.precedences = graph.precedences.clone();
+        closure.edge_constraints.required = graph.edge_constraints.required.clone();
+
+        Ok(MetricClosure { graph: closure, next })
+    }
+
+    /// Graph to solve on: edge weights are shortest path lengths
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+
+    /// Cities passed through on the shortest path from -> to (empty for a direct edge)
+    pub fn pass_through(&self, from: usize, to: usize) -> Vec<usize> {
+        let mut via = Vec::new();
+        let mut current = from;
+        while let Some(hop) = self.next[current][to].filter(|&hop| hop != to) {
+            via.push(hop);
+            current = hop;
+        }
+        via
+    }
+
+    pub fn expand(&self, solution: TSPSolution) -> ClosureSolution {
+        let legs = solution.optimal_path
+            .windows(2)
+            .map(|w| ClosureLeg {
+                from: w[0],
+                to: w[1],
+                via: self.pass_through(w[0], w[1]),
+                cost: self.graph.get_distance(w[0], w[1]),
+            })
+            .collect();
+
+        ClosureSolution { solution, legs }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TSPSolver;
+
+    /// A - B - C - D on a line (both directions), no Hamiltonian tour without passing through
+    fn line_graph() -> Graph {
+        let matrix = vec![
+            vec![Some(0), Some(1), None,    None   ],
+            vec![Some(1), Some(0), Some(2), None   ],
+            vec![None,    Some(2), Some(0), Some(3)],
+            vec![None,    None,    Some(3), Some(0)],
+        ];
+        let mut graph = Graph::from_optional_matrix(matrix).unwrap();
+        graph.set_node_names(["A", "B", "C", "D"].iter().map(|s| s.to_string()).collect()).unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_closure_expands_legs() -> Result<()> {
+        let graph = line_graph();
+        assert!(TSPSolver::new(graph.clone())?.solve().is_err());
+
+        let closure = MetricClosure::new(&graph)?;
+        assert_eq!(closure.graph().edge_weight(3, 0), Some(6));
+
+        let route = closure.expand(TSPSolver::new(closure.graph().clone())?.solve()?);
+        assert_eq!(route.solution.optimal_cost, 12);
+        assert_eq!(route.solution.optimal_path, vec![0, 1, 2, 3, 0]);
+        assert_eq!(route.legs[3].via, vec![2, 1]);
+        assert_eq!(route.expanded_path(), vec![0, 1, 2, 3, 2, 1, 0]);
+        assert_eq!(graph.calculate_path_cost(&route.expanded_path())?, 12);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_closure_negative_cycle() {
+        let mut graph = line_graph();
+        graph.set_edge(2, 1, -5).unwrap();
+
+        let message = MetricClosure::new(&graph).err().unwrap().to_string();
+        assert!(message.contains("Siklus negatif"), "{}", message);
+    }
+}
@@ -0,0 +1,522 @@
+/// Libraries
+use crate::heuristics::local_search;
+use crate::tsp_solver::SolverStats;
+use crate::{Graph, Result, TSPError};
+use std::time::Instant;
+
+/// Largest instance handled by the exact subset-partition DP (3^(n-1) work per salesman)
+const MAX_EXACT_SIZE: usize = 14;
+
+/// What the tours of all salesmen are optimized for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MultiObjective {
+    /// Total distance of every tour
+    #[default]
+    MinSum,
+    /// Length of the longest tour (ties broken by total distance)
+    MinMax,
+}
+
+/// (total, longest) cost of a set of tours
+type Score = (i32, i32);
+
+/// Result
+#[derive(Debug)]
+pub struct MultiTSPSolution {
+    pub tours: Vec<Vec<usize>>, // one closed tour per salesman, [depot] when idle
+    pub tour_costs: Vec<i32>,
+    pub total_cost: i32,
+    pub longest_tour: i32,
+    pub objective: MultiObjective,
+    pub depot: usize,
+    pub stats: SolverStats,
+    pub is_exact: bool,
+    pub is_valid: bool,
+}
+
+/// Multiple salesmen leaving from and returning to a common depot, every other city visited once
+pub struct MultiTSPSolver {
+    graph: Graph,
+    salesmen: usize,
+    depot: usize,
+    objective: MultiObjective,
+    min_stops: usize,
+    max_stops: Option<usize>,
+}
+
+impl MultiTSPSolver {
+    pub fn new(graph: Graph, salesmen: usize) -> Result<Self> {
+        graph.validate_for_tsp()?;
+
+        if graph.size < 2 {
+            return Err(TSPError::SolverError(
+                "Graf harus memiliki minimal 2 node".to_string()
+            ));
+        }
+        if salesmen == 0 {
+            return Err(TSPError::SolverError("Jumlah salesman minimal 1".to_string()));
+        }
+
+        Ok(MultiTSPSolver {
+            graph,
+            salesmen,
+            depot: 0,
+            objective: MultiObjective::default(),
+            min_stops: 1,
+            max_stops: None,
+        })
+    }
+
+    pub fn with_depot(mut self, depot: usize) -> Self {
+        self.depot = depot;
+        self
+    }
+
+    pub fn with_objective(mut self, objective: MultiObjective) -> Self {
+        self.objective = objective;
+        self
+    }
+
+    /// Cities each salesman must visit (default: at least 1, no maximum; 0 allows idle salesmen)
+    pub fn with_stop_limits(mut self, min_stops: usize, max_stops: Option<usize>) -> Self {
+        self.min_stops = min_stops;
+        self.max_stops = max_stops;
+        self
+    }
+
+    /// Exact: best tour of every subset of cities, then an optimal partition into m subsets
+    pub fn solve(&self) -> Result<MultiTSPSolution> {
+        self.validate()?;
+        if self.graph.size > MAX_EXACT_SIZE {
+            return Err(TSPError::SolverError(
+                format!("Graf terlalu besar (>{} node) untuk DP mTSP, gunakan heuristik", MAX_EXACT_SIZE)
+            ));
+        }
+
+        let mut stats = SolverStats {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        };
+
+        let customers = self.customers();
+        let full = (1usize << customers.len()) - 1;
+        let (tour_costs, paths) = subset_tours(&self.graph, self.depot, &customers, &mut stats);
+
+        // best[k][mask]: score of k salesmen covering exactly `mask`, choice[k][mask]: subset of the k-th one
+        let mut best: Vec<Vec<Option<Score>>> = vec![vec![None; full + 1]; self.salesmen + 1];
+        let mut choice: Vec<Vec<usize>> = vec![vec![0; full + 1]; self.salesmen + 1];
+        best[0][0] = Some((0, 0));
+
+        for k in 1..=self.salesmen {
+            for mask in 0..=full {
+                // The k-th salesman takes a subset holding the lowest remaining city (or nothing)
+                let low = mask & mask.wrapping_neg();
+                let mut sub = mask;
+                loop {
+                    let allowed = sub & low != 0 || sub == 0;
+                    if allowed && self.stops_allowed(sub.count_ones() as usize) {
+                        if let (Some(cost), Some(rest)) = (tour_costs[sub], best[k - 1][mask ^ sub]) {
+                            let score = (rest.0 + cost, rest.1.max(cost));
+                            stats.states_computed += 1;
+                            if best[k][mask].is_none_or(|current| self.is_better(score, current)) {
+                                best[k][mask] = Some(score);
+                                choice[k][mask] = sub;
+                            }
+                        }
+                    }
+
+                    if sub == 0 {
+                        break;
+                    }
+                    sub = (sub - 1) & mask;
+                }
+            }
+        }
+
+        if best[self.salesmen][full].is_none() {
+            return Err(TSPError::SolverError(
+                "Tidak ada pembagian kota yang memenuhi batas kunjungan per salesman".to_string()
+            ));
+        }
+
+        let mut tours = Vec::new();
+        let mut mask = full;
+        for k in (1..=self.salesmen).rev() {
+            let sub = choice[k][mask];
+            tours.push(paths[sub].clone());
+            mask ^= sub;
+        }
+        tours.sort_by_key(|tour| std::cmp::Reverse(tour.len()));
+
+        Ok(self.build_solution(tours, stats, true))
+    }
+
+    /// Route-first cluster-second: one giant nearest neighbour tour split optimally into m tours,
+    /// then each tour improved by local search
+    pub fn solve_heuristic(&self) -> Result<MultiTSPSolution> {
+        self.validate()?;
+
+        let mut stats = SolverStats {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        };
+
+        let order = self.giant_tour();
+        let n = order.len();
+        let segment_cost = |a: usize, b: usize| -> Option<i32> {
+            if a == b {
+                return Some(0);
+            }
+            let mut route = vec![self.depot];
+            route.extend_from_slice(&order[a..b]);
+            route.push(self.depot);
+            self.route_cost(&route)
+        };
+
+        // split[k][i]: best score covering order[..i] with k tours, from[k][i]: start of the k-th segment
+        let mut split: Vec<Vec<Option<Score>>> = vec![vec![None; n + 1]; self.salesmen + 1];
+        let mut from = vec![vec![0; n + 1]; self.salesmen + 1];
+        split[0][0] = Some((0, 0));
+
+        for k in 1..=self.salesmen {
+            for i in 0..=n {
+                for a in 0..=i {
+                    if !self.stops_allowed(i - a) {
+                        continue;
+                    }
+                    let (Some(rest), Some(cost)) = (split[k - 1][a], segment_cost(a, i)) else {
+                        continue;
+                    };
+
+                    let score = (rest.0 + cost, rest.1.max(cost));
+                    stats.states_computed += 1;
+                    if split[k][i].is_none_or(|current| self.is_better(score, current)) {
+                        split[k][i] = Some(score);
+                        from[k][i] = a;
+                    }
+                }
+            }
+        }
+
+        if split[self.salesmen][n].is_none() {
+            return Err(TSPError::SolverError(
+                "Heuristik tidak menemukan pembagian kota yang valid".to_string()
+            ));
+        }
+
+        let mut tours = Vec::new();
+        let mut end = n;
+        for k in (1..=self.salesmen).rev() {
+            let start = from[k][end];
+            let mut route = vec![self.depot];
+            route.extend_from_slice(&order[start..end]);
+            route.push(self.depot);
+
+            if route.len() > 3 {
+                let movable = 1..route.len() - 1;
+                if let Some((_, improved)) = local_search(route.clone(), movable, |r| self.route_cost(r), &mut stats) {
+                    route = improved;
+                }
+            }
+            tours.push(if route.len() == 2 { vec![self.depot] } else { route });
+            end = start;
+        }
+        tours.sort_by_key(|tour| std::cmp::Reverse(tour.len()));
+
+        Ok(self.build_solution(tours, stats, false))
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.depot >= self.graph.size {
+            return Err(TSPError::SolverError(
+                format!("Depot {} di luar jangkauan graf", self.depot)
+            ));
+        }
+
+        let cities = self.graph.size - 1;
+        if let Some(max_stops) = self.max_stops {
+            if max_stops < self.min_stops || max_stops * self.salesmen < cities {
+                return Err(TSPError::SolverError(
+                    format!("Batas maksimum {} kota per salesman tidak cukup untuk {} kota", max_stops, cities)
+                ));
+            }
+        }
+        if self.min_stops * self.salesmen > cities {
+            return Err(TSPError::SolverError(
+                format!("{} salesman dengan minimum {} kota memerlukan lebih dari {} kota",
+                        self.salesmen, self.min_stops, cities)
+            ));
+        }
+        Ok(())
+    }
+
+    fn customers(&self) -> Vec<usize> {
+        (0..self.graph.size).filter(|&v| v != self.depot).collect()
+    }
+
+    fn stops_allowed(&self, stops: usize) -> bool {
+        stops >= self.min_stops && self.max_stops.is_none_or(|max| stops <= max)
+    }
+
+    fn is_better(&self, score: Score, current: Score) -> bool {
+        match self.objective {
+            MultiObjective::MinSum => score < current,
+            MultiObjective::MinMax => (score.1, score.0) < (current.1, current.0),
+        }
+    }
+
+    /// Nearest neighbour order of every customer, starting from the depot
+    fn giant_tour(&self) -> Vec<usize> {
+        let mut remaining = self.customers();
+        let mut order = Vec::new();
+        let mut current = self.depot;
+
+        while !remaining.is_empty() {
+            let index = (0..remaining.len())
+                .min_by_key(|&i| {
+                    let city = remaining[i];
+                    let distance = if self.graph.can_travel(current, city) {
+                        self.graph.get_distance(current, city)
+                    } else {
+                        i32::MAX
+                    };
+                    (distance, city)
+                })
+                .unwrap_or(0);
+            current = remaining.remove(index);
+            order.push(current);
+        }
+        order
+    }
+
+    fn route_cost(&self, route: &[usize]) -> Option<i32> {
+        route.windows(2).try_fold(0, |total, w| {
+            self.graph.can_travel(w[0], w[1]).then(|| total + self.graph.get_distance(w[0], w[1]))
+        })
+    }
+
+    fn build_solution(&self, tours: Vec<Vec<usize>>, mut stats: SolverStats, is_exact: bool) -> MultiTSPSolution {
+        let tour_costs: Vec<i32> = tours.iter()
+            .map(|tour| if tour.len() < 2 { 0 } else { self.route_cost(tour).unwrap_or(0) })
+            .collect();
+
+        // Every tour starts and ends at the depot, every other city appears in exactly one tour
+        let mut visits = vec![0usize; self.graph.size];
+        for tour in &tours {
+            for &city in tour.iter().filter(|&&city| city != self.depot) {
+                visits[city] += 1;
+            }
+        }
+        let is_valid = tours.iter().all(|tour| {
+            tour.first() == Some(&self.depot)
+                && tour.last() == Some(&self.depot)
+                && (tour.len() < 2 || self.route_cost(tour).is_some())
+                && self.stops_allowed(tour.len().saturating_sub(2))
+        }) && visits.iter().enumerate().all(|(city, &count)| city == self.depot || count == 1);
+
+        stats.solve_duration = stats.start_time.map(|t| t.elapsed());
+
+        MultiTSPSolution {
+            total_cost: tour_costs.iter().sum(),
+            longest_tour: tour_costs.iter().max().copied().unwrap_or(0),
+            tours,
+            tour_costs,
+            objective: self.objective,
+            depot: self.depot,
+            stats,
+            is_exact,
+            is_valid,
+        }
+    }
+}
+
+/// Cheapest closed tour from the depot through every subset of `customers` (Held-Karp per subset),
+/// indexed by the subset mask over positions in `customers`
+pub(crate) fn subset_tours(
+    graph: &Graph,
+    depot: usize,
+    customers: &[usize],
+    stats: &mut SolverStats,
+) -> (Vec<Option<i32>>, Vec<Vec<usize>>) {
+    let c = customers.len();
+    let subsets = 1usize << c;
+
+    // path[mask][k]: cheapest depot -> ... -> customers[k] path through `mask`
+    let mut path: Vec<Vec<Option<i32>>> = vec![vec![None; c]; subsets];
+    for k in 0..c {
+        if graph.can_travel(depot, customers[k]) {
+            path[1 << k][k] = Some(graph.get_distance(depot, customers[k]));
+        }
+    }
+
+    for mask in 1..subsets {
+        for last in 0..c {
+            let Some(cost) = path[mask][last] else {
+                continue;
+            };
+            for next in (0..c).filter(|&next| mask & (1 << next) == 0) {
+                if !graph.can_travel(customers[last], customers[next]) {
+                    continue;
+                }
+                let total = cost + graph.get_distance(customers[last], customers[next]);
+                let entry = &mut path[mask | (1 << next)][next];
+                if entry.is_none_or(|current| total < current) {
+                    *entry = Some(total);
+                }
+                stats.states_computed += 1;
+            }
+        }
+    }
+
+    let mut tour_costs = vec![None; subsets];
+    let mut tours = vec![vec![depot]; subsets];
+    tour_costs[0] = Some(0);
+
+    for mask in 1..subsets {
+        let closing = (0..c)
+            .filter_map(|k| {
+                let cost = path[mask][k]?;
+                graph.can_travel(customers[k], depot)
+                    .then(|| (cost + graph.get_distance(customers[k], depot), k))
+            })
+            .min();
+        let Some((cost, mut last)) = closing else {
+            continue;
+        };
+        tour_costs[mask] = Some(cost);
+
+        // Walk back through the predecessors that reproduce each path cost
+        let mut reversed = vec![depot, customers[last]];
+        let mut remaining = mask;
+        while remaining.count_ones() > 1 {
+            let target = path[remaining][last];
+            let previous = remaining ^ (1 << last);
+            let prev = (0..c).find(|&k| {
+                previous & (1 << k) != 0
+                    && graph.can_travel(customers[k], customers[last])
+                    && path[previous][k].map(|p| p + graph.get_distance(customers[k], customers[last])) == target
+            });
+            let Some(prev) = prev else {
+                break;
+            };
+            reversed.push(customers[prev]);
+            remaining = previous;
+            last = prev;
+        }
+        reversed.push(depot);
+        reversed.reverse();
+        tours[mask] = reversed;
+    }
+
+    (tour_costs, tours)
+}
+
+impl std::fmt::Display for MultiTSPSolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "=== MULTIPLE TSP SOLUTION ===")?;
+        writeln!(f, "Method: {}", if self.is_exact { "Exact DP" } else { "Heuristic" })?;
+        writeln!(f, "Objective: {:?}", self.objective)?;
+        writeln!(f, "Total Cost: {}", self.total_cost)?;
+        writeln!(f, "Longest Tour: {}", self.longest_tour)?;
+        for (i, tour) in self.tours.iter().enumerate() {
+            writeln!(f, "Salesman {}: {} (cost {})", i + 1,
+                    tour.iter()
+                        .map(|&v| (v + 1).to_string())
+                        .collect::<Vec<_>>()
+                        .join(" -> "),
+                    self.tour_costs[i])?;
+        }
+        writeln!(f, "Valid: {}", if self.is_valid { "Yes" } else { "No" })?;
+        writeln!(f, "{}", self.stats)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TSPSolver;
+
+    /// Two clusters (1, 2) and (3, 4) on opposite sides of the depot
+    fn clustered_graph() -> Graph {
+        let matrix = vec![
+            vec![ 0, 10, 10, 10, 10],
+            vec![10,  0,  2, 18, 18],
+            vec![10,  2,  0, 18, 18],
+            vec![10, 18, 18,  0,  2],
+            vec![10, 18, 18,  2,  0],
+        ];
+        Graph::new(matrix).unwrap()
+    }
+
+    #[test]
+    fn test_single_salesman_matches_tsp() -> Result<()> {
+        let graph = clustered_graph();
+        let multi = MultiTSPSolver::new(graph.clone(), 1)?.solve()?;
+        let single = TSPSolver::new(graph)?.solve()?;
+
+        assert!(multi.is_valid);
+        assert_eq!(multi.total_cost, single.optimal_cost);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_two_salesmen_objectives() -> Result<()> {
+        // Each salesman serves one cluster: 10 + 2 + 10 per tour
+        for objective in [MultiObjective::MinSum, MultiObjective::MinMax] {
+            let solution = MultiTSPSolver::new(clustered_graph(), 2)?
+                .with_objective(objective)
+                .solve()?;
+
+            assert!(solution.is_valid);
+            assert_eq!(solution.tour_costs, vec![22, 22]);
+
+            let heuristic = MultiTSPSolver::new(clustered_graph(), 2)?
+                .with_objective(objective)
+                .solve_heuristic()?;
+            assert!(heuristic.is_valid);
+            assert_eq!(heuristic.total_cost, 44);
+        }
+
+        // Allowing idle salesmen, min-sum prefers one tour through both clusters (cost 42)
+        let solution = MultiTSPSolver::new(clustered_graph(), 2)?
+            .with_stop_limits(0, None)
+            .solve()?;
+        assert_eq!(solution.total_cost, 42);
+        assert_eq!(solution.tours[1], vec![0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stop_limits() -> Result<()> {
+        // At most 3 cities each forces a 3 + 1 split at best
+        let solution = MultiTSPSolver::new(clustered_graph(), 2)?
+            .with_stop_limits(1, Some(3))
+            .with_objective(MultiObjective::MinMax)
+            .solve()?;
+        assert!(solution.tours.iter().all(|tour| tour.len() - 2 <= 3));
+
+        assert!(MultiTSPSolver::new(clustered_graph(), 3)?.with_stop_limits(2, None).solve().is_err());
+        assert!(MultiTSPSolver::new(clustered_graph(), 2)?.with_stop_limits(0, Some(1)).solve().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_forbidden_edges() -> Result<()> {
+        // 1 and 2 can no longer be neighbours: each salesman pairs one city of each cluster
+        let mut graph = clustered_graph();
+        graph.forbid_edge(1, 2)?;
+        graph.forbid_edge(2, 1)?;
+        let solver = MultiTSPSolver::new(graph, 2)?;
+
+        for solution in [solver.solve()?, solver.solve_heuristic()?] {
+            assert!(solution.is_valid);
+            assert!(solution.tours.iter().all(|tour| !tour.windows(2).any(|w| w == [1, 2] || w == [2, 1])));
+        }
+
+        Ok(())
+    }
+}
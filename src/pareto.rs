@@ -0,0 +1,467 @@
+/// Libraries
+use crate::feasibility::FeasibilityReport;
+use crate::heuristics::local_search;
+use crate::tsp_solver::{RouteMode, SolverStats};
+use crate::{Graph, Result, TSPError};
+use std::collections::HashMap;
+use std::time::Instant;
+
+/// Largest instance handled by the exact label-setting DP
+const MAX_EXACT_SIZE: usize = 12;
+
+/// Resolution of the weight vectors tried by the heuristic (weights are multiples of 1/STEPS)
+const SCALARIZATION_STEPS: usize = 10;
+
+/// One non-dominated tour and its cost under every criterion
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParetoTour {
+    pub path: Vec<usize>,
+    pub costs: Vec<i32>,
+}
+
+/// Result
+#[derive(Debug)]
+pub struct ParetoFront {
+    pub criteria: Vec<String>,
+    pub tours: Vec<ParetoTour>, // sorted by cost vector
+    pub stats: SolverStats,
+    pub is_exact: bool,
+}
+
+impl ParetoFront {
+    /// Front as JSON (city names, one cost field per criterion)
+    pub fn to_json(&self, graph: &Graph) -> Result<String> {
+        let tours: Vec<serde_json::Value> = self.tours
+            .iter()
+            .map(|tour| {
+                let costs: serde_json::Map<String, serde_json::Value> = self.criteria
+                    .iter()
+                    .zip(&tour.costs)
+                    .map(|(name, &cost)| (name.clone(), cost.into()))
+                    .collect();
+                serde_json::json!({
+                    "path": tour.path.iter().map(|&city| graph.node_names[city].as_str()).collect::<Vec<_>>(),
+                    "costs": costs,
+                })
+            })
+            .collect();
+
+        serde_json::to_string_pretty(&serde_json::json!({
+            "criteria": self.criteria,
+            "exact": self.is_exact,
+            "tours": tours,
+        }))
+        .map_err(|e| TSPError::SolverError(format!("Gagal membuat JSON: {}", e)))
+    }
+}
+
+/// a is at least as good everywhere and strictly better somewhere
+fn dominates(a: &[i32], b: &[i32]) -> bool {
+    a.iter().zip(b).all(|(x, y)| x <= y) && a != b
+}
+
+/// Candidates no other candidate dominates, one per distinct cost vector (the first one found)
+fn non_dominated<T>(candidates: Vec<(Vec<i32>, T)>) -> Vec<(Vec<i32>, T)> {
+    let mut front: Vec<(Vec<i32>, T)> = Vec::new();
+    for (costs, item) in candidates {
+        if front.iter().any(|(kept, _)| *kept == costs || dominates(kept, &costs)) {
+            continue;
+        }
+        front.retain(|(kept, _)| !dominates(&costs, kept));
+        front.push((costs, item));
+    }
+    front.sort_by(|a, b| a.0.cmp(&b.0));
+    front
+}
+
+/// Partial route from the origin in the label-setting DP
+struct Label {
+    costs: Vec<i32>,
+    node: usize,
+    parent: Option<usize>,
+}
+
+/// Multi-criteria tour: every non-dominated trade-off between the graph's weight matrices
+pub struct ParetoSolver {
+    graph: Graph,
+    criteria: Vec<usize>,
+    origin: usize,
+}
+
+impl ParetoSolver {
+    pub fn new(graph: Graph) -> Result<Self> {
+        graph.validate_for_tsp()?;
+
+        if graph.size < 2 {
+            return Err(TSPError::SolverError(
+                "Graf harus memiliki minimal 2 node".to_string()
+            ));
+        }
+
+        Ok(ParetoSolver {
+            criteria: (0..graph.criteria().len()).collect(),
+            graph,
+            origin: 0,
+        })
+    }
+
+    /// Compare only these weight matrices (by name, in this order)
+    pub fn with_criteria(mut self, names: &[&str]) -> Result<Self> {
+        if names.is_empty() {
+            return Err(TSPError::SolverError("Diperlukan minimal 1 kriteria".to_string()));
+        }
+        self.criteria = names.iter().map(|name| self.graph.find_criterion(name)).collect::<Result<_>>()?;
+        Ok(self)
+    }
+
+    pub fn with_origin(mut self, origin: usize) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Exact DP over (visited set, last city) keeping every non-dominated cost vector per state
+    pub fn solve(&self) -> Result<ParetoFront> {
+        self.check()?;
+        if self.graph.size > MAX_EXACT_SIZE {
+            return Err(TSPError::SolverError(
+                format!("Graf terlalu besar (>{} node) untuk DP Pareto, gunakan heuristik", MAX_EXACT_SIZE)
+            ));
+        }
+
+        let mut stats = SolverStats {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        };
+
+        let origin_bit = 1u64 << self.origin;
+        let customers: Vec<usize> = (0..self.graph.size).filter(|&v| v != self.origin).collect();
+        let ready = |mask: u64, j: usize| self.graph.predecessors_mask(j) & !(mask | origin_bit) == 0;
+
+        let mut labels: Vec<Label> = Vec::new();
+        let mut layer: HashMap<(u64, usize), Vec<usize>> = HashMap::new();
+        for &j in customers.iter().filter(|&&j| self.usable(self.origin, j) && ready(0, j)) {
+            let costs = self.extend(&vec![0; self.criteria.len()], self.origin, j);
+            Self::insert_label(&mut labels, &mut layer, 1u64 << j, Label { costs, node: j, parent: None }, &mut stats);
+        }
+
+        for _ in 1..customers.len() {
+            let mut next_layer: HashMap<(u64, usize), Vec<usize>> = HashMap::new();
+            let mut keys: Vec<_> = layer.keys().copied().collect();
+            keys.sort_unstable();
+
+            for (mask, node) in keys {
+                for &index in &layer[&(mask, node)] {
+                    for &j in customers.iter().filter(|&&j| mask & (1u64 << j) == 0 && ready(mask, j)) {
+                        if !self.usable(node, j) {
+                            continue;
+                        }
+                        let costs = self.extend(&labels[index].costs, node, j);
+                        Self::insert_label(&mut labels, &mut next_layer, mask | (1u64 << j), Label {
+                            costs, node: j, parent: Some(index),
+                        }, &mut stats);
+                    }
+                }
+            }
+            layer = next_layer;
+        }
+
+        let mut closed: Vec<(Vec<i32>, usize)> = Vec::new();
+        let mut keys: Vec<_> = layer.keys().copied().collect();
+        keys.sort_unstable();
+        for key in keys {
+            for &index in &layer[&key] {
+                let label = &labels[index];
+                if self.usable(label.node, self.origin) {
+                    closed.push((self.extend(&label.costs, label.node, self.origin), index));
+                }
+            }
+        }
+
+        let tours = non_dominated(closed)
+            .into_iter()
+            .map(|(costs, mut index)| {
+                let mut path = vec![self.origin];
+                loop {
+                    path.push(labels[index].node);
+                    match labels[index].parent {
+                        Some(parent) => index = parent,
+                        None => break,
+                    }
+                }
+                path.push(self.origin);
+                path.reverse();
+                ParetoTour { path, costs }
+            })
+            .collect();
+
+        self.build_front(tours, stats, true)
+    }
+
+    /// Weighted-sum scalarizations (criteria scaled by their largest edge), each solved with
+    /// nearest neighbour + local search; the non-dominated results approximate the front
+    pub fn solve_heuristic(&self) -> Result<ParetoFront> {
+        self.check()?;
+
+        let mut stats = SolverStats {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        };
+
+        let scales: Vec<f64> = self.criteria
+            .iter()
+            .map(|&criterion| {
+                let largest = (0..self.graph.size)
+                    .flat_map(|i| (0..self.graph.size).map(move |j| (i, j)))
+                    .filter(|&(i, j)| self.graph.has_edge(i, j))
+                    .map(|(i, j)| self.graph.criterion_weight(criterion, i, j).abs())
+                    .max()
+                    .unwrap_or(0);
+                largest.max(1) as f64
+            })
+            .collect();
+
+        let mut found: Vec<(Vec<i32>, Vec<usize>)> = Vec::new();
+        for weights in weight_vectors(self.criteria.len(), SCALARIZATION_STEPS) {
+            let scalar = |costs: &[i32]| -> f64 {
+                costs.iter().zip(&weights).zip(&scales)
+                    .map(|((&cost, &weight), &scale)| weight as f64 * cost as f64 / scale)
+                    .sum()
+            };
+
+            let Some(route) = self.nearest_neighbour(|from, to| scalar(&self.extend(&vec![0; self.criteria.len()], from, to))) else {
+                continue;
+            };
+            let evaluate = |candidate: &[usize]| self.route_costs(candidate).map(|costs| scalar(&costs));
+            let movable = 1..route.len() - 1;
+            if let Some((_, route)) = local_search(route, movable, evaluate, &mut stats) {
+                if let Some(costs) = self.route_costs(&route) {
+                    found.push((costs, route));
+                }
+            }
+        }
+
+        let tours = non_dominated(found)
+            .into_iter()
+            .map(|(costs, path)| ParetoTour { path, costs })
+            .collect();
+
+        self.build_front(tours, stats, false)
+    }
+
+    fn check(&self) -> Result<()> {
+        if self.origin >= self.graph.size {
+            return Err(TSPError::SolverError(
+                format!("Kota asal {} di luar jangkauan graf", self.origin)
+            ));
+        }
+        FeasibilityReport::analyze(&self.graph, &RouteMode::Tour { origin: self.origin }).into_result()
+    }
+
+    fn usable(&self, from: usize, to: usize) -> bool {
+        self.graph.has_edge(from, to) && self.graph.edge_constraints.allows(from, to)
+    }
+
+    /// `costs` followed by the arc from -> to
+    fn extend(&self, costs: &[i32], from: usize, to: usize) -> Vec<i32> {
+        costs.iter()
+            .zip(&self.criteria)
+            .map(|(&cost, &criterion)| cost + self.graph.criterion_weight(criterion, from, to))
+            .collect()
+    }
+
+    /// Cost vector of a tour, None when it uses an unusable arc or breaks a precedence
+    fn route_costs(&self, route: &[usize]) -> Option<Vec<i32>> {
+        if !self.graph.precedence_violations(route).is_empty() {
+            return None;
+        }
+        route.windows(2).try_fold(vec![0; self.criteria.len()], |costs, w| {
+            self.usable(w[0], w[1]).then(|| self.extend(&costs, w[0], w[1]))
+        })
+    }
+
+    fn nearest_neighbour<F: Fn(usize, usize) -> f64>(&self, weight: F) -> Option<Vec<usize>> {
+        let mut visited = vec![false; self.graph.size];
+        visited[self.origin] = true;
+        let mut route = vec![self.origin];
+        let mut current = self.origin;
+
+        while let Some(next) = (0..self.graph.size)
+            .filter(|&j| !visited[j] && self.usable(current, j))
+            .filter(|&j| self.graph.precedences.iter().all(|&(before, after)| after != j || visited[before]))
+            .min_by(|&a, &b| weight(current, a).total_cmp(&weight(current, b)).then(a.cmp(&b)))
+        {
+            visited[next] = true;
+            route.push(next);
+            current = next;
+        }
+
+        if visited.iter().any(|&v| !v) {
+            return None;
+        }
+        route.push(self.origin);
+        Some(route)
+    }
+
+    /// Add a label unless one with the same key is at least as good; drop those it dominates
+    fn insert_label(
+        labels: &mut Vec<Label>,
+        layer: &mut HashMap<(u64, usize), Vec<usize>>,
+        mask: u64,
+        label: Label,
+        stats: &mut SolverStats,
+    ) {
+        let bucket = layer.entry((mask, label.node)).or_default();
+        if bucket.iter().any(|&i| labels[i].costs == label.costs || dominates(&labels[i].costs, &label.costs)) {
+            stats.cache_hits += 1;
+            return;
+        }
+
+        bucket.retain(|&i| !dominates(&label.costs, &labels[i].costs));
+        bucket.push(labels.len());
+        labels.push(label);
+        stats.states_computed += 1;
+        stats.max_memory_states = stats.max_memory_states.max(labels.len());
+    }
+
+    fn build_front(&self, tours: Vec<ParetoTour>, mut stats: SolverStats, is_exact: bool) -> Result<ParetoFront> {
+        if tours.is_empty() {
+            return Err(TSPError::SolverError(
+                "Tidak ada tour yang memenuhi batasan precedence dan edge".to_string()
+            ));
+        }
+
+        stats.solve_duration = stats.start_time.map(|t| t.elapsed());
+        let names = self.graph.criteria();
+        Ok(ParetoFront {
+            criteria: self.criteria.iter().map(|&criterion| names[criterion].to_string()).collect(),
+            tours,
+            stats,
+            is_exact,
+        })
+    }
+}
+
+/// Every vector of `k` non-negative integers summing to `steps`
+fn weight_vectors(k: usize, steps: usize) -> Vec<Vec<usize>> {
+    if k == 1 {
+        return vec![vec![steps]];
+    }
+    (0..=steps)
+        .flat_map(|first| {
+            weight_vectors(k - 1, steps - first).into_iter().map(move |mut rest| {
+                rest.insert(0, first);
+                rest
+            })
+        })
+        .collect()
+}
+
+impl std::fmt::Display for ParetoFront {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "=== PARETO FRONT ===")?;
+        writeln!(f, "Method: {}", if self.is_exact { "Exact DP" } else { "Heuristic" })?;
+        writeln!(f, "Criteria: {}", self.criteria.join(", "))?;
+        for tour in &self.tours {
+            writeln!(f, "{:?}: {}", tour.costs,
+                    tour.path.iter()
+                        .map(|&i| (i + 1).to_string())
+                        .collect::<Vec<_>>()
+                        .join(" -> "))?;
+        }
+        writeln!(f, "{}", self.stats)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TSPSolver;
+
+    /// Document example with a travel-time matrix favouring other arcs
+    fn two_criteria_graph() -> Graph {
+        let mut graph = Graph::new(vec![
+            vec![0, 10, 15, 20, 12],
+            vec![5,  0,  9, 10,  8],
+            vec![6, 13,  0, 12,  7],
+            vec![8,  8,  9,  0,  6],
+            vec![11, 7,  4,  9,  0],
+        ]).unwrap();
+        graph.add_weight_matrix("waktu", vec![
+            vec![0, 30,  5, 12, 20],
+            vec![9,  0, 25,  6, 14],
+            vec![7,  8,  0, 30,  5],
+            vec![4, 11,  9,  0, 28],
+            vec![22, 3, 17,  6,  0],
+        ]).unwrap();
+        graph
+    }
+
+    /// Brute force over every tour from city 0
+    fn brute_force_front(solver: &ParetoSolver) -> Vec<Vec<i32>> {
+        fn permute(rest: &mut Vec<usize>, k: usize, out: &mut Vec<Vec<usize>>) {
+            if k == rest.len() {
+                out.push(rest.clone());
+            }
+            for i in k..rest.len() {
+                rest.swap(k, i);
+                permute(rest, k + 1, out);
+                rest.swap(k, i);
+            }
+        }
+
+        let mut orders = Vec::new();
+        permute(&mut (1..solver.graph.size).collect(), 0, &mut orders);
+        let candidates = orders.into_iter()
+            .filter_map(|order| {
+                let mut path = vec![0];
+                path.extend(order);
+                path.push(0);
+                solver.route_costs(&path).map(|costs| (costs, ()))
+            })
+            .collect();
+        non_dominated(candidates).into_iter().map(|(costs, _)| costs).collect()
+    }
+
+    #[test]
+    fn test_exact_front_matches_brute_force() -> Result<()> {
+        let solver = ParetoSolver::new(two_criteria_graph())?;
+        let front = solver.solve()?;
+
+        assert_eq!(front.criteria, vec!["jarak", "waktu"]);
+        assert!(front.tours.len() > 1);
+        assert_eq!(front.tours.iter().map(|t| t.costs.clone()).collect::<Vec<_>>(), brute_force_front(&solver));
+        for tour in &front.tours {
+            assert_eq!(solver.route_costs(&tour.path).as_ref(), Some(&tour.costs));
+        }
+
+        // The cheapest distance on the front is the plain TSP optimum
+        let optimum = TSPSolver::new(two_criteria_graph())?.solve()?;
+        assert_eq!(front.tours[0].costs[0], optimum.optimal_cost);
+
+        let json = front.to_json(&solver.graph)?;
+        assert!(json.contains("\"waktu\""), "{}", json);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_heuristic_front_is_non_dominated() -> Result<()> {
+        let solver = ParetoSolver::new(two_criteria_graph())?;
+        let exact = solver.solve()?;
+        let heuristic = solver.solve_heuristic()?;
+
+        assert!(!heuristic.is_exact);
+        for tour in &heuristic.tours {
+            assert_eq!(solver.route_costs(&tour.path).as_ref(), Some(&tour.costs));
+            assert!(heuristic.tours.iter().all(|other| !dominates(&other.costs, &tour.costs)));
+            assert!(!exact.tours.iter().any(|best| dominates(&tour.costs, &best.costs)));
+        }
+
+        // A single criterion collapses the front to one tour
+        let single = ParetoSolver::new(two_criteria_graph())?.with_criteria(&["waktu"])?.solve()?;
+        assert_eq!(single.tours.len(), 1);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,365 @@
+/// Libraries
+use crate::graph::TourVerification;
+use crate::heuristics::local_search;
+use crate::tsp_solver::{RouteMode, SolverStats};
+use crate::{Graph, Result, TSPError, TSPSolver};
+use std::time::Instant;
+
+/// Goods picked up at `pickup` and dropped at `delivery`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeliveryRequest {
+    pub pickup: usize,
+    pub delivery: usize,
+    pub load: i32,
+}
+
+/// Load on board as a function of the cities already visited
+#[derive(Debug, Clone)]
+pub(crate) struct LoadProfile {
+    requests: Vec<DeliveryRequest>,
+    capacity: i32,
+    depot: usize, // deliveries here are served by the final return
+}
+
+impl LoadProfile {
+    /// Every request picked up but not yet delivered is on board (goods for the depot stay
+    /// on board until the tour closes)
+    pub fn load(&self, visited: impl Fn(usize) -> bool) -> i32 {
+        self.requests
+            .iter()
+            .filter(|request| visited(request.pickup) && (request.delivery == self.depot || !visited(request.delivery)))
+            .map(|request| request.load)
+            .sum()
+    }
+
+    pub fn fits(&self, visited: impl Fn(usize) -> bool) -> bool {
+        self.load(visited) <= self.capacity
+    }
+
+    /// Load on board after each stop of a route (a repeated city changes nothing, except the
+    /// return to the depot, which unloads its deliveries)
+    pub fn route_loads(&self, size: usize, path: &[usize]) -> Vec<i32> {
+        let mut visited = vec![false; size];
+        path.iter()
+            .enumerate()
+            .map(|(step, &node)| {
+                if step > 0 && node == self.depot && visited[node] {
+                    return self.load(|v| visited[v])
+                        - self.requests.iter()
+                            .filter(|request| request.delivery == self.depot && visited[request.pickup])
+                            .map(|request| request.load)
+                            .sum::<i32>();
+                }
+                visited[node] = true;
+                self.load(|v| visited[v])
+            })
+            .collect()
+    }
+}
+
+/// Result
+#[derive(Debug)]
+pub struct PickupDeliverySolution {
+    pub total_cost: i32,
+    pub path: Vec<usize>,
+    pub loads: Vec<i32>, // load on board after each stop of `path`
+    pub capacity: i32,
+    pub stats: SolverStats,
+    pub is_exact: bool,
+    pub is_valid: bool,
+    pub verification: TourVerification,
+}
+
+/// Pickup-and-delivery tour: each pickup precedes its delivery and the load never exceeds the capacity
+pub struct PickupDeliverySolver {
+    graph: Graph,
+    profile: LoadProfile,
+    origin: usize,
+}
+
+impl PickupDeliverySolver {
+    pub fn new(graph: Graph, requests: Vec<DeliveryRequest>, capacity: i32) -> Result<Self> {
+        if capacity <= 0 {
+            return Err(TSPError::InvalidGraph(
+                format!("Kapasitas kendaraan harus > 0 (nilai: {})", capacity)
+            ));
+        }
+
+        for request in &requests {
+            if request.pickup >= graph.size || request.delivery >= graph.size {
+                return Err(TSPError::InvalidGraph(
+                    format!("Permintaan ({}, {}) di luar jangkauan graf", request.pickup, request.delivery)
+                ));
+            }
+            if request.pickup == request.delivery {
+                return Err(TSPError::InvalidGraph(
+                    format!("Pickup dan delivery tidak boleh di kota yang sama ({})", graph.node_names[request.pickup])
+                ));
+            }
+            if request.load <= 0 || request.load > capacity {
+                return Err(TSPError::InvalidGraph(
+                    format!("Muatan {} -> {} harus di antara 1 dan kapasitas {} (nilai: {})",
+                            graph.node_names[request.pickup], graph.node_names[request.delivery],
+                            capacity, request.load)
+                ));
+            }
+        }
+
+        graph.validate_for_tsp()?;
+        if graph.size < 2 {
+            return Err(TSPError::SolverError(
+                "Graf harus memiliki minimal 2 node".to_string()
+            ));
+        }
+
+        Ok(PickupDeliverySolver {
+            graph,
+            profile: LoadProfile { requests, capacity, depot: 0 },
+            origin: 0,
+        })
+    }
+
+    pub fn with_origin(mut self, origin: usize) -> Self {
+        self.origin = origin;
+        self.profile.depot = origin;
+        self
+    }
+
+    /// The graph with a pickup -> delivery precedence per request; a delivery at the origin
+    /// needs none, the final return serves it
+    fn routing_graph(&self) -> Result<Graph> {
+        RouteMode::Tour { origin: self.origin }.validate(self.graph.size)?;
+
+        // Everything picked up at the origin is on board from the first leg
+        let depot_load = self.profile.load(|v| v == self.origin);
+        if depot_load > self.profile.capacity {
+            return Err(TSPError::SolverError(
+                format!("Tidak ada rute yang memenuhi kapasitas kendaraan: muatan dari {} ({}) melebihi kapasitas {}",
+                        self.graph.node_names[self.origin], depot_load, self.profile.capacity)
+            ));
+        }
+
+        let mut graph = self.graph.clone();
+        for request in self.profile.requests.iter().filter(|request| request.delivery != self.origin) {
+            graph.add_precedence(request.pickup, request.delivery)?;
+        }
+        graph.validate_for_tsp()?;
+        Ok(graph)
+    }
+
+    /// Held-Karp with precedence and capacity pruning
+    pub fn solve(&self) -> Result<PickupDeliverySolution> {
+        let start_time = Instant::now();
+        let graph = self.routing_graph()?;
+        let mut solver = TSPSolver::new(graph.clone())?
+            .with_mode(RouteMode::Tour { origin: self.origin })
+            .with_load_profile(self.profile.clone());
+
+        let (cost, path) = solver.solve_best_tour()?.ok_or_else(|| TSPError::SolverError(
+            "Tidak ada rute yang memenuhi urutan pickup-delivery dan kapasitas kendaraan".to_string()
+        ))?;
+
+        let stats = SolverStats {
+            start_time: Some(start_time),
+            ..solver.get_stats().clone()
+        };
+        Ok(self.build_solution(&graph, cost, path, stats, true))
+    }
+
+    /// Greedy feasible construction, then local search minimizing (violations, overload, cost)
+    pub fn solve_heuristic(&self) -> Result<PickupDeliverySolution> {
+        let graph = self.routing_graph()?;
+
+        let mut stats = SolverStats {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        };
+
+        let route = self.greedy_route(&graph);
+        let movable = 1..route.len() - 1;
+        let evaluate = |candidate: &[usize]| {
+            let cost = candidate.windows(2).try_fold(0, |total, w| {
+                graph.can_travel(w[0], w[1]).then(|| total + graph.get_distance(w[0], w[1]))
+            })?;
+            Some((graph.precedence_violations(candidate).len(), self.overload(candidate), cost))
+        };
+
+        let ((violations, overload, cost), path) = local_search(route, movable, evaluate, &mut stats)
+            .ok_or_else(|| TSPError::SolverError("Heuristik tidak menemukan rute".to_string()))?;
+
+        if violations > 0 || overload > 0 {
+            return Err(TSPError::SolverError(
+                "Heuristik tidak menemukan rute yang memenuhi urutan pickup-delivery dan kapasitas".to_string()
+            ));
+        }
+
+        Ok(self.build_solution(&graph, cost, path, stats, false))
+    }
+
+    /// Nearest city whose pickup is done and whose load fits (falls back to ignoring the capacity)
+    fn greedy_route(&self, graph: &Graph) -> Vec<usize> {
+        let mut visited = vec![false; self.graph.size];
+        visited[self.origin] = true;
+        let mut route = vec![self.origin];
+        let mut current = self.origin;
+
+        while route.len() < self.graph.size {
+            let unvisited: Vec<usize> = (0..self.graph.size).filter(|&j| !visited[j]).collect();
+            let ready: Vec<usize> = unvisited.iter()
+                .copied()
+                .filter(|&j| self.graph.can_travel(current, j))
+                .filter(|&j| graph.precedences.iter().all(|&(before, after)| after != j || visited[before]))
+                .collect();
+            let fitting: Vec<usize> = ready.iter()
+                .copied()
+                .filter(|&j| self.profile.fits(|v| v == j || visited[v]))
+                .collect();
+
+            let Some(next) = [fitting, ready, unvisited]
+                .into_iter()
+                .find(|pool| !pool.is_empty())
+                .and_then(|pool| pool.into_iter().min_by_key(|&j| (!self.graph.can_travel(current, j), self.graph.get_distance(current, j), j)))
+            else {
+                break;
+            };
+
+            visited[next] = true;
+            route.push(next);
+            current = next;
+        }
+
+        route.push(self.origin);
+        route
+    }
+
+    /// Total load above the capacity over every stop
+    fn overload(&self, path: &[usize]) -> i32 {
+        self.profile
+            .route_loads(self.graph.size, path)
+            .iter()
+            .map(|&load| (load - self.profile.capacity).max(0))
+            .sum()
+    }
+
+    fn build_solution(&self, graph: &Graph, cost: i32, path: Vec<usize>, mut stats: SolverStats, is_exact: bool) -> PickupDeliverySolution {
+        let loads = self.profile.route_loads(graph.size, &path);
+        let verification = graph.verify_route(&path, &RouteMode::Tour { origin: self.origin }, Some(cost));
+        stats.solve_duration = stats.start_time.map(|t| t.elapsed());
+
+        PickupDeliverySolution {
+            total_cost: cost,
+            is_valid: verification.is_valid() && loads.iter().all(|&load| load <= self.profile.capacity),
+            path,
+            loads,
+            capacity: self.profile.capacity,
+            stats,
+            is_exact,
+            verification,
+        }
+    }
+}
+
+impl std::fmt::Display for PickupDeliverySolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "=== PICKUP AND DELIVERY SOLUTION ===")?;
+        writeln!(f, "Method: {}", if self.is_exact { "Exact DP" } else { "Heuristic" })?;
+        writeln!(f, "Total Cost: {}", self.total_cost)?;
+        writeln!(f, "Capacity: {}", self.capacity)?;
+        writeln!(f, "Path: {}",
+                self.path.iter()
+                    .map(|&i| (i + 1).to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> "))?;
+        writeln!(f, "Max Load: {}", self.loads.iter().max().copied().unwrap_or(0))?;
+        writeln!(f, "Valid: {}", if self.is_valid { "Yes" } else { "No" })?;
+        writeln!(f, "{}", self.stats)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document_graph() -> Graph {
+        let matrix = vec![
+            vec![0, 10, 15, 20],
+            vec![5,  0,  9, 10],
+            vec![6, 13,  0, 12],
+            vec![8,  8,  9,  0],
+        ];
+        Graph::new(matrix).unwrap()
+    }
+
+    fn request(pickup: usize, delivery: usize, load: i32) -> DeliveryRequest {
+        DeliveryRequest { pickup, delivery, load }
+    }
+
+    #[test]
+    fn test_pickup_before_delivery() -> Result<()> {
+        // Pickup at 2 for delivery at 1 rules out the 35 tour 0 -> 1 -> 3 -> 2 -> 0
+        let solver = PickupDeliverySolver::new(document_graph(), vec![request(2, 1, 3)], 5)?;
+
+        let exact = solver.solve()?;
+        assert!(exact.is_valid);
+        assert_eq!(exact.total_cost, 40);
+        assert_eq!(exact.path, vec![0, 2, 3, 1, 0]);
+        assert_eq!(exact.loads, vec![0, 3, 3, 0, 0]);
+
+        let heuristic = solver.solve_heuristic()?;
+        assert!(heuristic.is_valid);
+        assert_eq!(heuristic.total_cost, 40);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_capacity_limits_simultaneous_loads() -> Result<()> {
+        // Both loads must be on board before reaching 3
+        let requests = vec![request(1, 3, 3), request(2, 3, 3)];
+
+        let solver = PickupDeliverySolver::new(document_graph(), requests.clone(), 5)?;
+        assert!(solver.solve().is_err());
+        assert!(solver.solve_heuristic().is_err());
+
+        let solution = PickupDeliverySolver::new(document_graph(), requests, 6)?.solve()?;
+        assert!(solution.is_valid);
+        assert_eq!(solution.total_cost, 39);
+        assert_eq!(solution.loads.iter().max(), Some(&6));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_delivery_to_depot() -> Result<()> {
+        // Goods from 2 come back to the depot: on board from 2 until the tour closes
+        let solver = PickupDeliverySolver::new(document_graph(), vec![request(2, 0, 4), request(1, 3, 2)], 5)?;
+
+        let exact = solver.solve()?;
+        assert!(exact.is_valid);
+        assert_eq!(exact.total_cost, 35);
+        assert_eq!(exact.path, vec![0, 1, 3, 2, 0]);
+        assert_eq!(exact.loads, vec![0, 2, 0, 4, 0]);
+
+        let heuristic = solver.solve_heuristic()?;
+        assert!(heuristic.is_valid);
+        assert_eq!(*heuristic.loads.last().unwrap(), 0);
+
+        assert!(PickupDeliverySolver::new(document_graph(), vec![request(2, 2, 1)], 5).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_depot_pickups_exceed_capacity() -> Result<()> {
+        // Both loads leave the depot together: 6 on board from the first leg
+        let solver = PickupDeliverySolver::new(document_graph(), vec![request(0, 1, 3), request(0, 2, 3)], 5)?;
+        assert!(solver.solve().is_err());
+        assert!(solver.solve_heuristic().is_err());
+
+        let solver = PickupDeliverySolver::new(document_graph(), vec![request(0, 1, 3), request(0, 2, 3)], 6)?;
+        assert!(solver.solve()?.is_valid);
+
+        Ok(())
+    }
+}
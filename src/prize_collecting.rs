@@ -0,0 +1,332 @@
+/// Libraries
+use crate::heuristics::local_search;
+use crate::multi_tsp::subset_tours;
+use crate::tsp_solver::SolverStats;
+use crate::{Graph, Result, TSPError};
+use std::time::Instant;
+
+/// Largest instance handled by the exact subset DP
+const MAX_EXACT_SIZE: usize = 16;
+
+/// Upper bound on add/drop passes of the heuristic
+const MAX_PASSES: usize = 1_000;
+
+/// Prize collected when a city is visited, penalty paid when it is skipped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CityReward {
+    pub prize: i32,
+    pub penalty: i32,
+}
+
+/// Which cities are worth visiting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectiveMode {
+    /// Maximize the collected prize with a tour no longer than `budget`
+    Orienteering { budget: i32 },
+    /// Minimize tour cost plus the penalties of skipped cities
+    PrizeCollecting,
+}
+
+/// Result
+#[derive(Debug)]
+pub struct SelectiveTourSolution {
+    pub path: Vec<usize>,
+    pub tour_cost: i32,
+    pub collected_prize: i32,
+    pub penalty_paid: i32,
+    pub skipped: Vec<usize>,
+    pub mode: SelectiveMode,
+    pub stats: SolverStats,
+    pub is_exact: bool,
+    pub is_valid: bool,
+}
+
+impl SelectiveTourSolution {
+    /// Orienteering: collected prize, prize-collecting: tour cost plus penalties
+    pub fn objective_value(&self) -> i32 {
+        match self.mode {
+            SelectiveMode::Orienteering { .. } => self.collected_prize,
+            SelectiveMode::PrizeCollecting => self.tour_cost + self.penalty_paid,
+        }
+    }
+}
+
+/// Tour through a chosen subset of cities (the origin is always visited)
+pub struct SelectiveTourSolver {
+    graph: Graph,
+    rewards: Vec<CityReward>,
+    mode: SelectiveMode,
+    origin: usize,
+}
+
+impl SelectiveTourSolver {
+    pub fn new(graph: Graph, rewards: Vec<CityReward>, mode: SelectiveMode) -> Result<Self> {
+        graph.validate_for_tsp()?;
+
+        if rewards.len() != graph.size {
+            return Err(TSPError::InvalidGraph(
+                format!("Jumlah data hadiah ({}) tidak sesuai dengan ukuran graf ({})",
+                        rewards.len(), graph.size)
+            ));
+        }
+        if let Some(city) = rewards.iter().position(|r| r.prize < 0 || r.penalty < 0) {
+            return Err(TSPError::InvalidGraph(
+                format!("Hadiah dan penalti {} tidak boleh negatif", graph.node_names[city])
+            ));
+        }
+        if let SelectiveMode::Orienteering { budget } = mode {
+            if budget < 0 {
+                return Err(TSPError::SolverError(
+                    format!("Anggaran jarak tidak boleh negatif (nilai: {})", budget)
+                ));
+            }
+        }
+
+        Ok(SelectiveTourSolver {
+            graph,
+            rewards,
+            mode,
+            origin: 0,
+        })
+    }
+
+    pub fn with_origin(mut self, origin: usize) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Exact: cheapest tour of every subset, then the best subset for the mode
+    pub fn solve(&self) -> Result<SelectiveTourSolution> {
+        self.check_origin()?;
+        if self.graph.size > MAX_EXACT_SIZE {
+            return Err(TSPError::SolverError(
+                format!("Graf terlalu besar (>{} node) untuk DP eksak, gunakan heuristik", MAX_EXACT_SIZE)
+            ));
+        }
+
+        let mut stats = SolverStats {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        };
+
+        let others: Vec<usize> = (0..self.graph.size).filter(|&v| v != self.origin).collect();
+        let (tour_costs, tours) = subset_tours(&self.graph, self.origin, &others, &mut stats);
+
+        let best = (0..tour_costs.len())
+            .filter_map(|mask| tour_costs[mask].and_then(|cost| Some((self.score(&tours[mask], cost)?, mask))))
+            .min()
+            .map(|(_, mask)| mask)
+            .ok_or_else(|| TSPError::SolverError("Tidak ada tour yang memenuhi anggaran".to_string()))?;
+
+        Ok(self.build_solution(tours[best].clone(), stats, true))
+    }
+
+    /// Greedy add/drop of cities with local search on the order of the visited ones
+    pub fn solve_heuristic(&self) -> Result<SelectiveTourSolution> {
+        self.check_origin()?;
+
+        let mut stats = SolverStats {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        };
+
+        let mut route = vec![self.origin, self.origin];
+        let mut best_key = self.evaluate(&route).ok_or_else(|| {
+            TSPError::SolverError("Tidak ada tour yang memenuhi anggaran".to_string())
+        })?;
+
+        for _ in 0..MAX_PASSES {
+            let mut best_move: Option<((i32, i32), Vec<usize>)> = None;
+            let mut consider = |candidate: Vec<usize>, stats: &mut SolverStats| {
+                stats.states_computed += 1;
+                if let Some(key) = self.evaluate(&candidate) {
+                    if key < best_key && best_move.as_ref().is_none_or(|(k, _)| key < *k) {
+                        best_move = Some((key, candidate));
+                    }
+                }
+            };
+
+            // Insert a skipped city anywhere, or drop a visited one
+            for city in (0..self.graph.size).filter(|city| !route.contains(city)) {
+                for position in 1..route.len() {
+                    let mut candidate = route.clone();
+                    candidate.insert(position, city);
+                    consider(candidate, &mut stats);
+                }
+            }
+            for position in 1..route.len() - 1 {
+                let mut candidate = route.clone();
+                candidate.remove(position);
+                consider(candidate, &mut stats);
+            }
+
+            let Some((_, candidate)) = best_move else {
+                break;
+            };
+            let movable = 1..candidate.len() - 1;
+            let Some((key, improved)) = local_search(candidate, movable, |r| self.evaluate(r), &mut stats) else {
+                break;
+            };
+            route = improved;
+            best_key = key;
+        }
+
+        let path = if route.len() == 2 { vec![self.origin] } else { route };
+        Ok(self.build_solution(path, stats, false))
+    }
+
+    fn check_origin(&self) -> Result<()> {
+        if self.origin >= self.graph.size {
+            return Err(TSPError::SolverError(
+                format!("Kota asal {} di luar jangkauan graf", self.origin)
+            ));
+        }
+        Ok(())
+    }
+
+    /// Key to minimize (None when the budget is exceeded)
+    fn score(&self, path: &[usize], cost: i32) -> Option<(i32, i32)> {
+        match self.mode {
+            SelectiveMode::Orienteering { budget } if cost > budget => None,
+            SelectiveMode::Orienteering { .. } => Some((-self.prize(path), cost)),
+            SelectiveMode::PrizeCollecting => Some((cost + self.penalty(path), cost)),
+        }
+    }
+
+    /// Score of a closed route, `[origin, origin]` being the empty tour
+    fn evaluate(&self, route: &[usize]) -> Option<(i32, i32)> {
+        let cost = if route.len() <= 2 {
+            0
+        } else {
+            route.windows(2).try_fold(0, |total, w| {
+                self.graph.can_travel(w[0], w[1]).then(|| total + self.graph.get_distance(w[0], w[1]))
+            })?
+        };
+        self.score(route, cost)
+    }
+
+    fn prize(&self, path: &[usize]) -> i32 {
+        (0..self.graph.size).filter(|v| path.contains(v)).map(|v| self.rewards[v].prize).sum()
+    }
+
+    fn penalty(&self, path: &[usize]) -> i32 {
+        (0..self.graph.size).filter(|v| !path.contains(v)).map(|v| self.rewards[v].penalty).sum()
+    }
+
+    fn build_solution(&self, path: Vec<usize>, mut stats: SolverStats, is_exact: bool) -> SelectiveTourSolution {
+        let tour_cost = if path.len() < 2 { 0 } else { self.graph.calculate_path_cost(&path).unwrap_or(0) };
+        let skipped: Vec<usize> = (0..self.graph.size).filter(|v| !path.contains(v)).collect();
+
+        // Closed at the origin, no repeated city, every edge present, budget respected
+        let body = if path.len() > 1 { &path[..path.len() - 1] } else { &path[..] };
+        let mut seen = vec![false; self.graph.size];
+        let no_repeats = body.iter().all(|&v| !std::mem::replace(&mut seen[v], true));
+        let is_valid = path.first() == Some(&self.origin)
+            && path.last() == Some(&self.origin)
+            && no_repeats
+            && (path.len() < 2 || self.graph.calculate_path_cost(&path).is_ok())
+            && self.score(&path, tour_cost).is_some();
+
+        stats.solve_duration = stats.start_time.map(|t| t.elapsed());
+
+        SelectiveTourSolution {
+            tour_cost,
+            collected_prize: self.prize(&path),
+            penalty_paid: self.penalty(&path),
+            path,
+            skipped,
+            mode: self.mode,
+            stats,
+            is_exact,
+            is_valid,
+        }
+    }
+}
+
+impl std::fmt::Display for SelectiveTourSolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "=== SELECTIVE TOUR SOLUTION ===")?;
+        writeln!(f, "Method: {}", if self.is_exact { "Exact DP" } else { "Heuristic" })?;
+        writeln!(f, "Mode: {:?}", self.mode)?;
+        writeln!(f, "Path: {}",
+                self.path.iter()
+                    .map(|&i| (i + 1).to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> "))?;
+        writeln!(f, "Tour Cost: {}", self.tour_cost)?;
+        writeln!(f, "Collected Prize: {}", self.collected_prize)?;
+        writeln!(f, "Penalty Paid: {}", self.penalty_paid)?;
+        writeln!(f, "Skipped: {:?}", self.skipped.iter().map(|&i| i + 1).collect::<Vec<_>>())?;
+        writeln!(f, "Valid: {}", if self.is_valid { "Yes" } else { "No" })?;
+        writeln!(f, "{}", self.stats)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document_graph() -> Graph {
+        let matrix = vec![
+            vec![0, 10, 15, 20],
+            vec![5,  0,  9, 10],
+            vec![6, 13,  0, 12],
+            vec![8,  8,  9,  0],
+        ];
+        Graph::new(matrix).unwrap()
+    }
+
+    fn rewards(values: &[(i32, i32)]) -> Vec<CityReward> {
+        values.iter().map(|&(prize, penalty)| CityReward { prize, penalty }).collect()
+    }
+
+    #[test]
+    fn test_orienteering_budget() -> Result<()> {
+        // 0 -> 1 -> 2 -> 0 costs exactly 25, node 3 does not fit
+        let rewards = rewards(&[(0, 0), (5, 0), (8, 0), (3, 0)]);
+        let solver = SelectiveTourSolver::new(document_graph(), rewards, SelectiveMode::Orienteering { budget: 25 })?;
+
+        for solution in [solver.solve()?, solver.solve_heuristic()?] {
+            assert!(solution.is_valid);
+            assert_eq!(solution.collected_prize, 13);
+            assert_eq!(solution.tour_cost, 25);
+            assert_eq!(solution.skipped, vec![3]);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_prize_collecting_penalties() -> Result<()> {
+        // Visiting only 2 costs 21 + penalties 3 = 24, cheaper than any other choice
+        let rewards = rewards(&[(0, 0), (0, 1), (0, 30), (0, 2)]);
+        let solver = SelectiveTourSolver::new(document_graph(), rewards, SelectiveMode::PrizeCollecting)?;
+
+        for solution in [solver.solve()?, solver.solve_heuristic()?] {
+            assert!(solution.is_valid);
+            assert_eq!(solution.path, vec![0, 2, 0]);
+            assert_eq!(solution.objective_value(), 24);
+            assert_eq!(solution.skipped, vec![1, 3]);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_forbidden_edge() -> Result<()> {
+        // Penalties force every city; without 1 -> 3 the best tour costs 39
+        let mut graph = document_graph();
+        graph.forbid_edge(1, 3)?;
+        let rewards = rewards(&[(0, 0), (0, 100), (0, 100), (0, 100)]);
+        let solver = SelectiveTourSolver::new(graph, rewards, SelectiveMode::PrizeCollecting)?;
+
+        for solution in [solver.solve()?, solver.solve_heuristic()?] {
+            assert!(solution.is_valid);
+            assert_eq!(solution.path, vec![0, 1, 2, 3, 0]);
+            assert_eq!(solution.tour_cost, 39);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,355 @@
+/// Libraries
+use crate::feasibility::FeasibilityReport;
+use crate::graph::TourVerification;
+use crate::heuristics::{feasible_tours, held_karp_tour};
+use crate::tsp_solver::{RouteMode, SolverStats};
+use crate::{Graph, HeuristicSolver, Result, TSPError};
+use std::collections::{HashMap, HashSet};
+use std::time::Instant;
+
+/// Largest instance for the Held-Karp DPs (worst case, regret of a given tour)
+const MAX_EXACT_SIZE: usize = 16;
+
+/// Largest instance for the exact min-max regret search (every tour is enumerated)
+const MAX_REGRET_SIZE: usize = 8;
+
+/// Range of possible costs of an edge
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CostInterval {
+    pub low: i32,
+    pub high: i32,
+}
+
+impl CostInterval {
+    /// Nominal estimate used by the non-robust solvers
+    pub fn midpoint(&self) -> i32 {
+        (self.low + self.high).div_euclid(2)
+    }
+}
+
+/// The cost of arc from -> to lies somewhere in `interval` instead of being the matrix weight
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EdgeInterval {
+    pub from: usize,
+    pub to: usize,
+    pub interval: CostInterval,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RobustObjective {
+    /// Smallest worst-case gap to the optimal tour of the same scenario
+    #[default]
+    MinMaxRegret,
+    /// Smallest cost when every edge takes its upper bound
+    WorstCaseCost,
+}
+
+/// Scenario with the largest regret for a tour: its own edges at their upper bound, every other
+/// edge at its lower bound. `rival` is the optimal tour of that scenario.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegretScenario {
+    pub regret: i32,
+    pub rival: Vec<usize>,
+    pub rival_cost: i32,
+}
+
+/// Result
+#[derive(Debug)]
+pub struct RobustSolution {
+    pub path: Vec<usize>,
+    pub objective: RobustObjective,
+    pub best_case_cost: i32,  // every edge at its lower bound
+    pub worst_case_cost: i32, // every edge at its upper bound
+    pub scenario: Option<RegretScenario>, // None when the instance is too large to evaluate
+    pub stats: SolverStats,
+    pub is_exact: bool,
+    pub is_valid: bool,
+    pub verification: TourVerification,
+}
+
+/// TSP with interval edge costs: minimize the worst-case regret or the worst-case cost
+pub struct RobustSolver {
+    graph: Graph,
+    intervals: HashMap<(usize, usize), CostInterval>,
+    objective: RobustObjective,
+    origin: usize,
+}
+
+impl RobustSolver {
+    pub fn new(graph: Graph, intervals: Vec<EdgeInterval>) -> Result<Self> {
+        graph.validate_for_tsp()?;
+
+        if graph.size < 2 {
+            return Err(TSPError::SolverError(
+                "Graf harus memiliki minimal 2 node".to_string()
+            ));
+        }
+
+        let mut by_edge = HashMap::new();
+        for edge in intervals {
+            if edge.from >= graph.size || edge.to >= graph.size || !graph.has_edge(edge.from, edge.to) {
+                return Err(TSPError::InvalidGraph(
+                    format!("Interval biaya untuk edge yang tidak ada: ({}, {})", edge.from, edge.to)
+                ));
+            }
+            if edge.interval.low > edge.interval.high {
+                return Err(TSPError::InvalidGraph(
+                    format!("Interval biaya {}..{} tidak valid: batas bawah melebihi batas atas",
+                            edge.interval.low, edge.interval.high)
+                ));
+            }
+            if by_edge.insert((edge.from, edge.to), edge.interval).is_some() {
+                return Err(TSPError::InvalidGraph(
+                    format!("Interval biaya ganda untuk edge {} -> {}",
+                            graph.node_names[edge.from], graph.node_names[edge.to])
+                ));
+            }
+        }
+
+        Ok(RobustSolver {
+            graph,
+            intervals: by_edge,
+            objective: RobustObjective::default(),
+            origin: 0,
+        })
+    }
+
+    pub fn with_objective(mut self, objective: RobustObjective) -> Self {
+        self.objective = objective;
+        self
+    }
+
+    pub fn with_origin(mut self, origin: usize) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Regret: every tour is enumerated, a tour whose worst-case cost exceeds the all-upper optimum
+    /// by at least the best regret so far is skipped without a DP. Worst case: one Held-Karp.
+    pub fn solve(&self) -> Result<RobustSolution> {
+        self.check()?;
+        let limit = match self.objective {
+            RobustObjective::MinMaxRegret => MAX_REGRET_SIZE,
+            RobustObjective::WorstCaseCost => MAX_EXACT_SIZE,
+        };
+        if self.graph.size > limit {
+            return Err(TSPError::SolverError(
+                format!("Graf terlalu besar (>{} node) untuk solusi robust eksak, gunakan heuristik", limit)
+            ));
+        }
+
+        let mut stats = SolverStats {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        };
+
+        let (worst_optimum, worst_path) = self.best_tour(|i, j| self.cost_interval(i, j).high, &mut stats)
+            .ok_or_else(|| TSPError::SolverError("Tidak ada tour yang memenuhi batasan".to_string()))?;
+
+        let path = match self.objective {
+            RobustObjective::WorstCaseCost => worst_path,
+            RobustObjective::MinMaxRegret => {
+                let mut best: Option<(i32, Vec<usize>)> = None;
+                for tour in feasible_tours(&self.graph, self.origin) {
+                    let upper = self.tour_cost(&tour, |interval| interval.high);
+                    if best.as_ref().is_some_and(|(regret, _)| upper - worst_optimum >= *regret) {
+                        continue;
+                    }
+                    let Some(scenario) = self.regret_scenario(&tour, &mut stats) else {
+                        continue;
+                    };
+                    if best.as_ref().is_none_or(|(regret, _)| scenario.regret < *regret) {
+                        best = Some((scenario.regret, tour));
+                    }
+                }
+                best.map(|(_, tour)| tour).unwrap_or(worst_path)
+            }
+        };
+
+        Ok(self.build_solution(path, stats, true))
+    }
+
+    /// Regret: optimal tour of the midpoint scenario (a 2-approximation when solved exactly).
+    /// Worst case: nearest neighbour + local search on the upper bounds.
+    pub fn solve_heuristic(&self) -> Result<RobustSolution> {
+        self.check()?;
+
+        let mut stats = SolverStats {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        };
+
+        let scenario = |interval: CostInterval| match self.objective {
+            RobustObjective::MinMaxRegret => interval.midpoint(),
+            RobustObjective::WorstCaseCost => interval.high,
+        };
+        let path = if self.objective == RobustObjective::MinMaxRegret && self.graph.size <= MAX_EXACT_SIZE {
+            self.best_tour(|i, j| scenario(self.cost_interval(i, j)), &mut stats)
+                .map(|(_, path)| path)
+                .ok_or_else(|| TSPError::SolverError("Tidak ada tour yang memenuhi batasan".to_string()))?
+        } else {
+            let mut graph = self.graph.clone();
+            for (&(from, to), &interval) in &self.intervals {
+                graph.adjacency_matrix[from][to] = scenario(interval);
+            }
+            HeuristicSolver::new(graph)?
+                .with_mode(RouteMode::Tour { origin: self.origin })
+                .solve()?
+                .optimal_path
+        };
+
+        Ok(self.build_solution(path, stats, false))
+    }
+
+    fn check(&self) -> Result<()> {
+        if self.origin >= self.graph.size {
+            return Err(TSPError::SolverError(
+                format!("Kota asal {} di luar jangkauan graf", self.origin)
+            ));
+        }
+        FeasibilityReport::analyze(&self.graph, &RouteMode::Tour { origin: self.origin }).into_result()
+    }
+
+    /// Cost range of the arc (a single point when it is certain)
+    pub fn cost_interval(&self, from: usize, to: usize) -> CostInterval {
+        self.intervals.get(&(from, to)).copied().unwrap_or_else(|| {
+            let weight = self.graph.get_distance(from, to);
+            CostInterval { low: weight, high: weight }
+        })
+    }
+
+    fn tour_cost<F: Fn(CostInterval) -> i32>(&self, tour: &[usize], bound: F) -> i32 {
+        tour.windows(2).map(|w| bound(self.cost_interval(w[0], w[1]))).sum()
+    }
+
+    /// Worst scenario of `tour` and the regret it causes
+    pub fn regret_scenario(&self, tour: &[usize], stats: &mut SolverStats) -> Option<RegretScenario> {
+        let edges: HashSet<(usize, usize)> = tour.windows(2).map(|w| (w[0], w[1])).collect();
+        let weight = |i: usize, j: usize| {
+            let interval = self.cost_interval(i, j);
+            if edges.contains(&(i, j)) { interval.high } else { interval.low }
+        };
+
+        let (rival_cost, rival) = self.best_tour(weight, stats)?;
+        Some(RegretScenario {
+            regret: self.tour_cost(tour, |interval| interval.high) - rival_cost,
+            rival,
+            rival_cost,
+        })
+    }
+
+    /// Held-Karp from the origin under the given weights
+    fn best_tour<F: Fn(usize, usize) -> i32>(&self, weight: F, stats: &mut SolverStats) -> Option<(i32, Vec<usize>)> {
+        held_karp_tour(&self.graph, self.origin, 0, |cost, i, j| Some(cost + weight(i, j)), stats)
+    }
+
+    fn build_solution(&self, path: Vec<usize>, mut stats: SolverStats, is_exact: bool) -> RobustSolution {
+        let scenario = if self.graph.size <= MAX_EXACT_SIZE {
+            self.regret_scenario(&path, &mut stats)
+        } else {
+            None
+        };
+        let verification = self.graph.verify_route(&path, &RouteMode::Tour { origin: self.origin }, None);
+
+        stats.solve_duration = stats.start_time.map(|t| t.elapsed());
+
+        RobustSolution {
+            best_case_cost: self.tour_cost(&path, |interval| interval.low),
+            worst_case_cost: self.tour_cost(&path, |interval| interval.high),
+            path,
+            objective: self.objective,
+            scenario,
+            stats,
+            is_exact,
+            is_valid: verification.is_valid(),
+            verification,
+        }
+    }
+}
+
+impl std::fmt::Display for RobustSolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "=== ROBUST TSP SOLUTION ===")?;
+        writeln!(f, "Method: {}", if self.is_exact { "Exact" } else { "Heuristic" })?;
+        writeln!(f, "Objective: {}", match self.objective {
+            RobustObjective::MinMaxRegret => "min-max regret",
+            RobustObjective::WorstCaseCost => "worst-case cost",
+        })?;
+        writeln!(f, "Cost Range: [{}, {}]", self.best_case_cost, self.worst_case_cost)?;
+        if let Some(scenario) = &self.scenario {
+            writeln!(f, "Max Regret: {}", scenario.regret)?;
+        }
+        writeln!(f, "Path: {}",
+                self.path.iter()
+                    .map(|&i| (i + 1).to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> "))?;
+        writeln!(f, "Valid: {}", if self.is_valid { "Yes" } else { "No" })?;
+        writeln!(f, "{}", self.stats)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TSPSolver;
+
+    /// Document example where a few edges are uncertain
+    fn interval_graph() -> (Graph, Vec<EdgeInterval>) {
+        let graph = Graph::new(vec![
+            vec![0, 10, 15, 20, 12],
+            vec![5,  0,  9, 10,  8],
+            vec![6, 13,  0, 12,  7],
+            vec![8,  8,  9,  0,  6],
+            vec![11, 7,  4,  9,  0],
+        ]).unwrap();
+        let intervals = [(0, 1, 4, 20), (1, 3, 6, 18), (2, 0, 2, 9), (4, 2, 1, 15), (3, 4, 5, 7)]
+            .into_iter()
+            .map(|(from, to, low, high)| EdgeInterval { from, to, interval: CostInterval { low, high } })
+            .collect();
+        (graph, intervals)
+    }
+
+    #[test]
+    fn test_min_max_regret() -> Result<()> {
+        let (graph, intervals) = interval_graph();
+        let solver = RobustSolver::new(graph.clone(), intervals)?;
+        let solution = solver.solve()?;
+        let scenario = solution.scenario.clone().unwrap();
+
+        assert!(solution.is_valid);
+        assert!(solution.best_case_cost <= solution.worst_case_cost);
+        assert!(scenario.regret >= 0);
+        assert_eq!(solution.worst_case_cost - scenario.rival_cost, scenario.regret);
+
+        // No tour has a smaller worst-case regret
+        let mut stats = SolverStats::default();
+        for tour in feasible_tours(&graph, 0) {
+            let other = solver.regret_scenario(&tour, &mut stats).unwrap();
+            assert!(other.regret >= scenario.regret, "{:?}: {}", tour, other.regret);
+        }
+
+        let heuristic = solver.solve_heuristic()?;
+        assert!(heuristic.scenario.unwrap().regret >= scenario.regret);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_worst_case_cost() -> Result<()> {
+        let (graph, intervals) = interval_graph();
+        let solution = RobustSolver::new(graph.clone(), intervals.clone())?
+            .with_objective(RobustObjective::WorstCaseCost)
+            .solve()?;
+
+        let mut upper = graph;
+        for edge in &intervals {
+            upper.adjacency_matrix[edge.from][edge.to] = edge.interval.high;
+        }
+        let expected = TSPSolver::new(upper)?.solve()?;
+        assert_eq!(solution.worst_case_cost, expected.optimal_cost);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+/// Libraries
+use crate::tsp_solver::{EdgeConstraints, RouteMode};
+use crate::{Graph, Result, TSPSolver};
+
+/// Edges whose tolerance is at most this fraction of their weight are reported as fragile
+const FRAGILE_RATIO: f64 = 0.1;
+
+/// Sensitivity of one edge relative to the optimal route
+#[derive(Debug, Clone, PartialEq)]
+pub struct EdgeSensitivity {
+    pub from: usize,
+    pub to: usize,
+    pub weight: i32,
+    pub in_tour: bool,
+    /// In the tour: how much the weight may rise before another tour becomes strictly better.
+    /// Outside the tour: how much the weight must fall before the edge can enter an optimal tour.
+    /// None means no alternative tour exists (the answer never changes).
+    pub tolerance: Option<i32>,
+}
+
+impl EdgeSensitivity {
+    pub fn is_fragile(&self) -> bool {
+        match self.tolerance {
+            Some(tolerance) => tolerance as f64 <= self.weight as f64 * FRAGILE_RATIO,
+            None => false,
+        }
+    }
+}
+
+/// Per-edge sensitivity analysis of a solved instance
+#[derive(Debug, Clone)]
+pub struct SensitivityReport {
+    pub optimal_cost: i32,
+    pub optimal_path: Vec<usize>,
+    pub edges: Vec<EdgeSensitivity>,
+}
+
+impl SensitivityReport {
+    /// Every edge is re-solved with the exact solver: forbidden if it is in the tour, required otherwise
+    pub(crate) fn analyze(
+        graph: &Graph,
+        mode: RouteMode,
+        base: &EdgeConstraints,
+        optimal_cost: i32,
+        optimal_path: &[usize],
+    ) -> Result<Self> {
+        let tour_edges: Vec<(usize, usize)> = optimal_path
+            .windows(2)
+            .map(|w| (w[0], w[1]))
+            .collect();
+
+        let mut edges = Vec::new();
+        for &(from, to) in &tour_edges {
+            let mut constraints = base.clone();
+            constraints.forbidden.insert((from, to));
+            let alternative = Self::best_cost(graph, mode, constraints)?;
+
+            edges.push(EdgeSensitivity {
+                from,
+                to,
+                weight: graph.get_distance(from, to),
+                in_tour: true,
+                tolerance: alternative.map(|cost| cost - optimal_cost),
+            });
+        }
+
+        let mut outside = Vec::new();
+        for from in 0..graph.size {
+            for to in 0..graph.size {
+                if !graph.has_edge(from, to) || tour_edges.contains(&(from, to)) || !base.allows(from, to) {
+                    continue;
+                }
+
+                let mut constraints = base.clone();
+                constraints.required.push((from, to));
+                let alternative = Self::best_cost(graph, mode, constraints)?;
+
+                outside.push(EdgeSensitivity {
+                    from,
+                    to,
+                    weight: graph.get_distance(from, to),
+                    in_tour: false,
+                    tolerance: alternative.map(|cost| cost - optimal_cost),
+                });
+            }
+        }
+        outside.sort_by_key(|edge| (edge.tolerance.is_none(), edge.tolerance, edge.from, edge.to));
+        edges.extend(outside);
+
+        Ok(SensitivityReport {
+            optimal_cost,
+            optimal_path: optimal_path.to_vec(),
+            edges,
+        })
+    }
+
+    fn best_cost(graph: &Graph, mode: RouteMode, constraints: EdgeConstraints) -> Result<Option<i32>> {
+        let mut solver = TSPSolver::new(graph.clone())?
+            .with_mode(mode)
+            .with_edge_constraints(constraints);
+        Ok(solver.solve_best_tour()?.map(|(cost, _)| cost))
+    }
+
+    pub fn fragile_edges(&self) -> impl Iterator<Item = &EdgeSensitivity> {
+        self.edges.iter().filter(|edge| edge.is_fragile())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sensitivity_document_example() -> Result<()> {
+        let matrix = vec![
+            vec![0, 10, 15, 20],
+            vec![5,  0,  9, 10],
+            vec![6, 13,  0, 12],
+            vec![8,  8,  9,  0],
+        ];
+
+        let graph = Graph::new(matrix)?;
+        let mut solver = TSPSolver::new(graph)?;
+        let solution = solver.solve()?;
+        let report = solver.sensitivity_report()?;
+
+        assert_eq!(report.optimal_cost, 35);
+        assert_eq!(report.optimal_path, solution.optimal_path);
+        assert_eq!(report.edges.len(), 12);
+
+        // 1 -> 2 is shared with the 39 tour, so only the 40 tour replaces it
+        let tolerances: Vec<_> = report.edges.iter()
+            .filter(|e| e.in_tour)
+            .map(|e| e.tolerance)
+            .collect();
+        assert_eq!(tolerances, vec![Some(5), Some(4), Some(4), Some(4)]);
+
+        // Forcing 1 -> 3 gives at best 40
+        let edge = report.edges.iter().find(|e| e.from == 0 && e.to == 2).unwrap();
+        assert!(!edge.in_tour);
+        assert_eq!(edge.tolerance, Some(5));
+
+        Ok(())
+    }
+}
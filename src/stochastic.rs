@@ -0,0 +1,520 @@
+/// Libraries
+use crate::feasibility::FeasibilityReport;
+use crate::graph::TourVerification;
+use crate::heuristics::{feasible_tours, held_karp_tour, local_search};
+use crate::tsp_solver::{RouteMode, SolverStats};
+use crate::{Graph, HeuristicSolver, Result, TSPError};
+use std::collections::HashMap;
+use std::time::Instant;
+
+/// Largest instance for the expected-cost Held-Karp
+const MAX_EXACT_SIZE: usize = 16;
+
+/// Largest instance for the exact quantile search (every tour is enumerated)
+const MAX_QUANTILE_SIZE: usize = 8;
+
+/// Percentiles always reported next to the optimized one
+pub const REPORTED_PERCENTILES: [f64; 5] = [0.05, 0.25, 0.5, 0.75, 0.95];
+
+/// Small seeded PRNG (SplitMix64), so a seed always reproduces the same samples
+#[derive(Debug, Clone)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in [0, 1)
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Standard normal (Box–Muller)
+    pub fn next_normal(&mut self) -> f64 {
+        let u = 1.0 - self.next_f64(); // (0, 1], keeps ln finite
+        let v = self.next_f64();
+        (-2.0 * u.ln()).sqrt() * (2.0 * std::f64::consts::PI * v).cos()
+    }
+}
+
+/// Probability distribution of an edge cost
+#[derive(Debug, Clone, PartialEq)]
+pub enum CostDistribution {
+    Normal { mean: f64, std_dev: f64 },
+    Uniform { low: f64, high: f64 },
+    Empirical(Vec<f64>), // observed costs, each equally likely
+}
+
+impl CostDistribution {
+    pub fn mean(&self) -> f64 {
+        match self {
+            CostDistribution::Normal { mean, .. } => *mean,
+            CostDistribution::Uniform { low, high } => (low + high) / 2.0,
+            CostDistribution::Empirical(values) => values.iter().sum::<f64>() / values.len() as f64,
+        }
+    }
+
+    pub fn sample(&self, rng: &mut SplitMix64) -> f64 {
+        match self {
+            CostDistribution::Normal { mean, std_dev } => mean + std_dev * rng.next_normal(),
+            CostDistribution::Uniform { low, high } => low + (high - low) * rng.next_f64(),
+            CostDistribution::Empirical(values) => values[(rng.next_u64() % values.len() as u64) as usize],
+        }
+    }
+
+    fn validate(&self) -> Result<()> {
+        let valid = match self {
+            CostDistribution::Normal { mean, std_dev } => mean.is_finite() && std_dev.is_finite() && *std_dev >= 0.0,
+            CostDistribution::Uniform { low, high } => low.is_finite() && high.is_finite() && low <= high,
+            CostDistribution::Empirical(values) => !values.is_empty() && values.iter().all(|v| v.is_finite()),
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(TSPError::InvalidGraph(format!("Distribusi biaya tidak valid: {}", self)))
+        }
+    }
+}
+
+impl std::fmt::Display for CostDistribution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CostDistribution::Normal { mean, std_dev } => write!(f, "normal({}, {})", mean, std_dev),
+            CostDistribution::Uniform { low, high } => write!(f, "seragam({}, {})", low, high),
+            CostDistribution::Empirical(values) => write!(f, "empiris({} sampel)", values.len()),
+        }
+    }
+}
+
+/// The cost of arc from -> to follows `distribution` instead of the matrix weight
+#[derive(Debug, Clone, PartialEq)]
+pub struct EdgeDistribution {
+    pub from: usize,
+    pub to: usize,
+    pub distribution: CostDistribution,
+}
+
+/// Sample statistics of a tour cost
+#[derive(Debug, Clone)]
+pub struct CostSummary {
+    pub mean: f64,
+    pub variance: f64, // unbiased (n - 1)
+    pub std_dev: f64,
+    pub min: f64,
+    pub max: f64,
+    pub samples: Vec<f64>, // sorted ascending
+}
+
+impl CostSummary {
+    fn from_samples(mut samples: Vec<f64>) -> Self {
+        samples.sort_by(f64::total_cmp);
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = if samples.len() > 1 {
+            samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+
+        CostSummary {
+            mean,
+            variance,
+            std_dev: variance.sqrt(),
+            min: samples[0],
+            max: samples[samples.len() - 1],
+            samples,
+        }
+    }
+
+    /// Nearest-rank percentile, `q` in (0, 1]
+    pub fn quantile(&self, q: f64) -> f64 {
+        let rank = (q * self.samples.len() as f64).ceil() as usize;
+        self.samples[rank.clamp(1, self.samples.len()) - 1]
+    }
+}
+
+/// Draws every uncertain edge `samples` times up front; all tours are evaluated on the same
+/// scenarios (common random numbers), so comparisons between tours are not blurred by noise.
+pub struct MonteCarloEvaluator {
+    graph: Graph,
+    draws: HashMap<(usize, usize), Vec<f64>>,
+    samples: usize,
+}
+
+impl MonteCarloEvaluator {
+    pub fn new(graph: &Graph, distributions: &[EdgeDistribution], samples: usize, seed: u64) -> Result<Self> {
+        if samples == 0 {
+            return Err(TSPError::SolverError("Jumlah sampel Monte Carlo harus positif".to_string()));
+        }
+
+        let mut rng = SplitMix64::new(seed);
+        let mut draws = HashMap::new();
+        for edge in distributions {
+            if edge.from >= graph.size || edge.to >= graph.size {
+                return Err(TSPError::InvalidGraph(
+                    format!("Distribusi untuk edge di luar jangkauan graf: ({}, {})", edge.from, edge.to)
+                ));
+            }
+            if !graph.has_edge(edge.from, edge.to) {
+                return Err(TSPError::InvalidGraph(
+                    format!("Distribusi untuk edge yang tidak ada: {} -> {}",
+                            graph.node_names[edge.from], graph.node_names[edge.to])
+                ));
+            }
+            edge.distribution.validate()?;
+
+            let values = (0..samples).map(|_| edge.distribution.sample(&mut rng)).collect();
+            if draws.insert((edge.from, edge.to), values).is_some() {
+                return Err(TSPError::InvalidGraph(
+                    format!("Distribusi ganda untuk edge {} -> {}",
+                            graph.node_names[edge.from], graph.node_names[edge.to])
+                ));
+            }
+        }
+
+        Ok(MonteCarloEvaluator { graph: graph.clone(), draws, samples })
+    }
+
+    /// Cost of `path` in every scenario
+    pub fn sample_costs(&self, path: &[usize]) -> Result<Vec<f64>> {
+        let mut costs = vec![0.0; self.samples];
+        for w in path.windows(2) {
+            if !self.graph.has_edge(w[0], w[1]) {
+                return Err(TSPError::InvalidPath(
+                    format!("Tidak ada edge dari {} ke {}", self.graph.node_names[w[0]], self.graph.node_names[w[1]])
+                ));
+            }
+            match self.draws.get(&(w[0], w[1])) {
+                Some(values) => costs.iter_mut().zip(values).for_each(|(cost, value)| *cost += value),
+                None => {
+                    let weight = self.graph.get_distance(w[0], w[1]) as f64;
+                    costs.iter_mut().for_each(|cost| *cost += weight);
+                }
+            }
+        }
+        Ok(costs)
+    }
+
+    pub fn evaluate(&self, path: &[usize]) -> Result<CostSummary> {
+        self.sample_costs(path).map(CostSummary::from_samples)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum StochasticObjective {
+    /// Expected tour cost (linear, so the DP on edge means is exact)
+    #[default]
+    Expected,
+    /// The q-quantile of the tour cost over the Monte Carlo scenarios, e.g. 0.9
+    Quantile(f64),
+}
+
+/// Result
+#[derive(Debug)]
+pub struct StochasticSolution {
+    pub path: Vec<usize>,
+    pub objective: StochasticObjective,
+    pub objective_value: f64,
+    pub expected_cost: f64, // exact, from the distribution means
+    pub summary: CostSummary, // Monte Carlo
+    pub stats: SolverStats,
+    pub is_exact: bool,
+    pub is_valid: bool,
+    pub verification: TourVerification,
+}
+
+/// TSP with random edge costs: minimize the expected cost or a quantile of the cost
+pub struct StochasticSolver {
+    graph: Graph,
+    distributions: Vec<EdgeDistribution>,
+    objective: StochasticObjective,
+    origin: usize,
+    samples: usize,
+    seed: u64,
+}
+
+impl StochasticSolver {
+    pub fn new(graph: Graph, distributions: Vec<EdgeDistribution>) -> Result<Self> {
+        graph.validate_for_tsp()?;
+
+        if graph.size < 2 {
+            return Err(TSPError::SolverError(
+                "Graf harus memiliki minimal 2 node".to_string()
+            ));
+        }
+
+        Ok(StochasticSolver {
+            graph,
+            distributions,
+            objective: StochasticObjective::default(),
+            origin: 0,
+            samples: 1000,
+            seed: 42,
+        })
+    }
+
+    pub fn with_objective(mut self, objective: StochasticObjective) -> Self {
+        self.objective = objective;
+        self
+    }
+
+    pub fn with_origin(mut self, origin: usize) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    pub fn with_samples(mut self, samples: usize) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Expected cost: Held-Karp on the edge means. Quantile: every tour is evaluated on the
+    /// scenarios, exact with respect to the sample.
+    pub fn solve(&self) -> Result<StochasticSolution> {
+        let evaluator = self.prepare()?;
+        let limit = match self.objective {
+            StochasticObjective::Expected => MAX_EXACT_SIZE,
+            StochasticObjective::Quantile(_) => MAX_QUANTILE_SIZE,
+        };
+        if self.graph.size > limit {
+            return Err(TSPError::SolverError(
+                format!("Graf terlalu besar (>{} node) untuk solusi stokastik eksak, gunakan heuristik", limit)
+            ));
+        }
+
+        let mut stats = SolverStats {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        };
+
+        let path = match self.objective {
+            StochasticObjective::Expected => self.expected_tour(&mut stats),
+            StochasticObjective::Quantile(q) => feasible_tours(&self.graph, self.origin)
+                .into_iter()
+                .filter_map(|tour| {
+                    stats.states_computed += 1;
+                    evaluator.evaluate(&tour).ok().map(|summary| (summary.quantile(q), tour))
+                })
+                .min_by(|a, b| a.0.total_cmp(&b.0))
+                .map(|(_, tour)| tour),
+        }.ok_or_else(|| TSPError::SolverError("Tidak ada tour yang memenuhi batasan".to_string()))?;
+
+        self.build_solution(path, &evaluator, stats, true)
+    }
+
+    /// Expected-cost tour (DP when small, nearest neighbour on the means otherwise), then local
+    /// search on the objective over the scenarios
+    pub fn solve_heuristic(&self) -> Result<StochasticSolution> {
+        let evaluator = self.prepare()?;
+
+        let mut stats = SolverStats {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        };
+
+        let route = if self.graph.size <= MAX_EXACT_SIZE {
+            self.expected_tour(&mut stats)
+                .ok_or_else(|| TSPError::SolverError("Tidak ada tour yang memenuhi batasan".to_string()))?
+        } else {
+            let mut graph = self.graph.clone();
+            for edge in &self.distributions {
+                graph.adjacency_matrix[edge.from][edge.to] = edge.distribution.mean().round() as i32;
+            }
+            HeuristicSolver::new(graph)?
+                .with_mode(RouteMode::Tour { origin: self.origin })
+                .solve()?
+                .optimal_path
+        };
+
+        let movable = 1..route.len() - 1;
+        let evaluate = |candidate: &[usize]| {
+            if !self.feasible(candidate) {
+                return None;
+            }
+            match self.objective {
+                StochasticObjective::Expected => Some(self.expected_cost(candidate)),
+                StochasticObjective::Quantile(q) => evaluator.evaluate(candidate).ok().map(|summary| summary.quantile(q)),
+            }
+        };
+        let (_, path) = local_search(route, movable, evaluate, &mut stats)
+            .ok_or_else(|| TSPError::SolverError("Heuristik tidak menemukan rute yang valid".to_string()))?;
+
+        self.build_solution(path, &evaluator, stats, false)
+    }
+
+    fn prepare(&self) -> Result<MonteCarloEvaluator> {
+        if self.origin >= self.graph.size {
+            return Err(TSPError::SolverError(
+                format!("Kota asal {} di luar jangkauan graf", self.origin)
+            ));
+        }
+        if let StochasticObjective::Quantile(q) = self.objective {
+            if !(q > 0.0 && q <= 1.0) {
+                return Err(TSPError::SolverError(
+                    format!("Kuantil harus di antara 0 (eksklusif) dan 1: {}", q)
+                ));
+            }
+        }
+        FeasibilityReport::analyze(&self.graph, &RouteMode::Tour { origin: self.origin }).into_result()?;
+
+        MonteCarloEvaluator::new(&self.graph, &self.distributions, self.samples, self.seed)
+    }
+
+    fn feasible(&self, tour: &[usize]) -> bool {
+        self.graph.verify_route(tour, &RouteMode::Tour { origin: self.origin }, None).is_valid()
+    }
+
+    fn mean_weight(&self, from: usize, to: usize) -> f64 {
+        self.distributions.iter()
+            .find(|edge| edge.from == from && edge.to == to)
+            .map(|edge| edge.distribution.mean())
+            .unwrap_or_else(|| self.graph.get_distance(from, to) as f64)
+    }
+
+    fn expected_cost(&self, tour: &[usize]) -> f64 {
+        tour.windows(2).map(|w| self.mean_weight(w[0], w[1])).sum()
+    }
+
+    /// Held-Karp on the edge means
+    fn expected_tour(&self, stats: &mut SolverStats) -> Option<Vec<usize>> {
+        let n = self.graph.size;
+        let means: Vec<Vec<f64>> = (0..n).map(|i| (0..n).map(|j| self.mean_weight(i, j)).collect()).collect();
+
+        held_karp_tour(&self.graph, self.origin, 0.0, |cost, i, j| Some(cost + means[i][j]), stats)
+            .map(|(_, path)| path)
+    }
+
+    fn build_solution(&self, path: Vec<usize>, evaluator: &MonteCarloEvaluator, mut stats: SolverStats, is_exact: bool) -> Result<StochasticSolution> {
+        let summary = evaluator.evaluate(&path)?;
+        let expected_cost = self.expected_cost(&path);
+        let objective_value = match self.objective {
+            StochasticObjective::Expected => expected_cost,
+            StochasticObjective::Quantile(q) => summary.quantile(q),
+        };
+        let verification = self.graph.verify_route(&path, &RouteMode::Tour { origin: self.origin }, None);
+
+        stats.solve_duration = stats.start_time.map(|t| t.elapsed());
+
+        Ok(StochasticSolution {
+            path,
+            objective: self.objective,
+            objective_value,
+            expected_cost,
+            summary,
+            stats,
+            is_exact,
+            is_valid: verification.is_valid(),
+            verification,
+        })
+    }
+}
+
+impl std::fmt::Display for StochasticSolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "=== STOCHASTIC TSP SOLUTION ===")?;
+        writeln!(f, "Method: {}", if self.is_exact { "Exact" } else { "Heuristic" })?;
+        match self.objective {
+            StochasticObjective::Expected => writeln!(f, "Objective: expected cost")?,
+            StochasticObjective::Quantile(q) => writeln!(f, "Objective: {}% quantile", q * 100.0)?,
+        }
+        writeln!(f, "Objective Value: {:.2}", self.objective_value)?;
+        writeln!(f, "Mean: {:.2} (exact {:.2})", self.summary.mean, self.expected_cost)?;
+        writeln!(f, "Std Dev: {:.2}", self.summary.std_dev)?;
+        writeln!(f, "Path: {}",
+                self.path.iter()
+                    .map(|&i| (i + 1).to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> "))?;
+        writeln!(f, "Valid: {}", if self.is_valid { "Yes" } else { "No" })?;
+        writeln!(f, "{}", self.stats)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TSPSolver;
+
+    /// Document example; 0 -> 1 is cheap on average but occasionally very slow
+    fn stochastic_graph() -> (Graph, Vec<EdgeDistribution>) {
+        let graph = Graph::new(vec![
+            vec![0, 10, 15, 20],
+            vec![5,  0,  9, 10],
+            vec![6, 13,  0, 12],
+            vec![8,  8,  9,  0],
+        ]).unwrap();
+        let distributions = vec![
+            EdgeDistribution { from: 0, to: 1, distribution: CostDistribution::Empirical(vec![2.0, 2.0, 2.0, 2.0, 60.0]) },
+            EdgeDistribution { from: 1, to: 3, distribution: CostDistribution::Normal { mean: 10.0, std_dev: 2.0 } },
+            EdgeDistribution { from: 2, to: 0, distribution: CostDistribution::Uniform { low: 4.0, high: 8.0 } },
+        ];
+        (graph, distributions)
+    }
+
+    #[test]
+    fn test_monte_carlo_is_seeded() -> Result<()> {
+        let (graph, distributions) = stochastic_graph();
+        let path = [0, 1, 3, 2, 0];
+
+        let first = MonteCarloEvaluator::new(&graph, &distributions, 5000, 7)?.evaluate(&path)?;
+        let second = MonteCarloEvaluator::new(&graph, &distributions, 5000, 7)?.evaluate(&path)?;
+        assert_eq!(first.samples, second.samples);
+
+        // Exact mean is 13.6 + 10 + 9 + 6 = 38.6
+        assert!((first.mean - 38.6).abs() < 1.0, "{}", first.mean);
+        assert!(first.quantile(0.05) <= first.quantile(0.5) && first.quantile(0.5) <= first.quantile(0.95));
+        assert!(first.min >= 2.0 + 9.0 + 4.0 - 10.0 && first.max > 80.0);
+
+        // An edge outside the graph is rejected, not looked up
+        let outside = EdgeDistribution { from: 0, to: graph.size, distribution: distributions[0].distribution.clone() };
+        assert!(!graph.has_edge(0, graph.size));
+        assert!(MonteCarloEvaluator::new(&graph, &[outside], 10, 7).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_expected_and_quantile_objectives() -> Result<()> {
+        let (graph, distributions) = stochastic_graph();
+
+        let expected = StochasticSolver::new(graph.clone(), distributions.clone())?.solve()?;
+        let mut mean_graph = graph.clone();
+        mean_graph.adjacency_matrix[0][1] = 14; // 13.6 rounded, enough to keep the same optimum
+        mean_graph.adjacency_matrix[2][0] = 6;
+        let reference = TSPSolver::new(mean_graph)?.solve()?;
+        assert_eq!(expected.path, reference.optimal_path);
+        assert!((expected.expected_cost - 38.6).abs() < 1e-9);
+
+        // The 90% quantile avoids the edge with the heavy tail
+        let quantile = StochasticSolver::new(graph.clone(), distributions.clone())?
+            .with_objective(StochasticObjective::Quantile(0.9))
+            .solve()?;
+        assert!(quantile.is_valid);
+        assert!(!quantile.path.windows(2).any(|w| w == [0, 1]));
+        assert!(quantile.objective_value <= expected.summary.quantile(0.9));
+
+        let heuristic = StochasticSolver::new(graph, distributions)?
+            .with_objective(StochasticObjective::Quantile(0.9))
+            .solve_heuristic()?;
+        assert!(heuristic.objective_value >= quantile.objective_value);
+
+        Ok(())
+    }
+}
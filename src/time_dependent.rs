@@ -0,0 +1,336 @@
+/// Libraries
+use crate::feasibility::FeasibilityReport;
+use crate::graph::TourVerification;
+use crate::heuristics::{held_karp_tour, local_search};
+use crate::tsp_solver::{RouteMode, SolverStats};
+use crate::{Graph, Result, TSPError};
+use std::time::Instant;
+
+/// Largest instance handled by the exact DP
+const MAX_EXACT_SIZE: usize = 16;
+
+/// From `start` until the next slot, travel times are the weights of criterion `criterion`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeSlot {
+    pub start: i32,
+    pub criterion: usize, // index into `Graph::criteria`
+}
+
+/// One leg of the route: reached `from` at `ready`, left at `departure`, reached `to` at `arrival`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimedLeg {
+    pub from: usize,
+    pub to: usize,
+    pub ready: i32,
+    pub departure: i32,
+    pub arrival: i32,
+    pub criterion: usize, // matrix of the slot the leg departs in
+}
+
+/// Result
+#[derive(Debug)]
+pub struct TimeDependentSolution {
+    pub path: Vec<usize>,
+    pub legs: Vec<TimedLeg>,
+    pub start_time: i32,
+    pub duration: i32, // return to the origin minus the start time, waiting included
+    pub stats: SolverStats,
+    pub is_exact: bool,
+    pub is_valid: bool,
+    pub verification: TourVerification,
+}
+
+/// Time-dependent TSP: edge costs follow a piecewise schedule of weight matrices. Waiting for a
+/// later slot is allowed when it arrives sooner, so an earlier arrival is never worse and the
+/// DP on earliest arrival times stays exact.
+pub struct TimeDependentSolver {
+    graph: Graph,
+    slots: Vec<TimeSlot>,
+    origin: usize,
+    start_time: i32,
+}
+
+impl TimeDependentSolver {
+    pub fn new(graph: Graph, slots: Vec<TimeSlot>) -> Result<Self> {
+        graph.validate_for_tsp()?;
+
+        if graph.size < 2 {
+            return Err(TSPError::SolverError(
+                "Graf harus memiliki minimal 2 node".to_string()
+            ));
+        }
+
+        let mut slots = slots;
+        slots.sort_by_key(|slot| slot.start);
+        if slots.is_empty() {
+            return Err(TSPError::InvalidGraph("Jadwal matriks tidak boleh kosong".to_string()));
+        }
+        if let Some(pair) = slots.windows(2).find(|pair| pair[0].start == pair[1].start) {
+            return Err(TSPError::InvalidGraph(
+                format!("Dua slot jadwal dimulai pada waktu yang sama ({})", pair[0].start)
+            ));
+        }
+
+        let criteria = graph.criteria();
+        for slot in &slots {
+            let Some(name) = criteria.get(slot.criterion) else {
+                return Err(TSPError::InvalidGraph(
+                    format!("Slot jadwal {} memakai matriks yang tidak ada", slot.start)
+                ));
+            };
+            for (i, j) in (0..graph.size).flat_map(|i| (0..graph.size).map(move |j| (i, j))) {
+                if graph.has_edge(i, j) && graph.criterion_weight(slot.criterion, i, j) < 0 {
+                    return Err(TSPError::InvalidGraph(
+                        format!("Waktu tempuh {} -> {} pada matriks '{}' tidak boleh negatif",
+                                graph.node_names[i], graph.node_names[j], name)
+                    ));
+                }
+            }
+        }
+
+        Ok(TimeDependentSolver {
+            graph,
+            slots,
+            origin: 0,
+            start_time: 0,
+        })
+    }
+
+    pub fn with_origin(mut self, origin: usize) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    pub fn with_start_time(mut self, start_time: i32) -> Self {
+        self.start_time = start_time;
+        self
+    }
+
+    /// Held-Karp over (visited set, last city) keeping the earliest arrival
+    pub fn solve(&self) -> Result<TimeDependentSolution> {
+        self.check()?;
+        if self.graph.size > MAX_EXACT_SIZE {
+            return Err(TSPError::SolverError(
+                format!("Graf terlalu besar (>{} node) untuk DP bergantung waktu, gunakan heuristik",
+                        MAX_EXACT_SIZE)
+            ));
+        }
+
+        let mut stats = SolverStats {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        };
+
+        let arrive = |time: i32, from: usize, to: usize| self.travel(from, to, time).map(|(_, arrival, _)| arrival);
+        let (_, path) = held_karp_tour(&self.graph, self.origin, self.start_time, arrive, &mut stats)
+            .ok_or_else(|| {
+                TSPError::SolverError("Tidak ada tour yang memenuhi batasan precedence dan edge".to_string())
+            })?;
+
+        Ok(self.build_solution(path, stats, true))
+    }
+
+    /// Nearest neighbour by arrival time, then local search on the return time
+    pub fn solve_heuristic(&self) -> Result<TimeDependentSolution> {
+        self.check()?;
+
+        let mut stats = SolverStats {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        };
+
+        let mut visited = vec![false; self.graph.size];
+        visited[self.origin] = true;
+        let mut route = vec![self.origin];
+        let (mut current, mut time) = (self.origin, self.start_time);
+
+        while let Some((arrival, next)) = (0..self.graph.size)
+            .filter(|&j| !visited[j])
+            .filter(|&j| self.graph.precedences.iter().all(|&(before, after)| after != j || visited[before]))
+            .filter_map(|j| self.travel(current, j, time).map(|(_, arrival, _)| (arrival, j)))
+            .min()
+        {
+            visited[next] = true;
+            route.push(next);
+            (current, time) = (next, arrival);
+        }
+        if visited.iter().any(|&v| !v) {
+            return Err(TSPError::SolverError("Heuristik tidak menemukan rute".to_string()));
+        }
+        route.push(self.origin);
+
+        let movable = 1..route.len() - 1;
+        let evaluate = |candidate: &[usize]| {
+            if !self.graph.precedence_violations(candidate).is_empty() {
+                return None;
+            }
+            self.simulate(candidate).and_then(|legs| legs.last().map(|leg| leg.arrival))
+        };
+        let (_, path) = local_search(route, movable, evaluate, &mut stats)
+            .ok_or_else(|| TSPError::SolverError("Heuristik tidak menemukan rute yang valid".to_string()))?;
+
+        Ok(self.build_solution(path, stats, false))
+    }
+
+    fn check(&self) -> Result<()> {
+        if self.origin >= self.graph.size {
+            return Err(TSPError::SolverError(
+                format!("Kota asal {} di luar jangkauan graf", self.origin)
+            ));
+        }
+        FeasibilityReport::analyze(&self.graph, &RouteMode::Tour { origin: self.origin }).into_result()
+    }
+
+    /// Slot in force at `time` (the first slot also covers anything before it)
+    fn slot_at(&self, time: i32) -> &TimeSlot {
+        self.slots.iter().rev().find(|slot| slot.start <= time).unwrap_or(&self.slots[0])
+    }
+
+    /// Best (departure, arrival, criterion) for from -> to when ready at `time`: leave now or
+    /// wait for a later slot, whichever arrives first
+    fn travel(&self, from: usize, to: usize, time: i32) -> Option<(i32, i32, usize)> {
+        if !self.graph.has_edge(from, to) || !self.graph.edge_constraints.allows(from, to) {
+            return None;
+        }
+
+        let now = self.slot_at(time).criterion;
+        std::iter::once((time, now))
+            .chain(self.slots.iter().filter(|slot| slot.start > time).map(|slot| (slot.start, slot.criterion)))
+            .map(|(departure, criterion)| {
+                (departure, departure + self.graph.criterion_weight(criterion, from, to), criterion)
+            })
+            .min_by_key(|&(departure, arrival, _)| (arrival, departure))
+    }
+
+    /// Legs of a route started at the start time, None when an edge is unusable
+    fn simulate(&self, path: &[usize]) -> Option<Vec<TimedLeg>> {
+        let mut time = self.start_time;
+        path.windows(2)
+            .map(|w| {
+                let (departure, arrival, criterion) = self.travel(w[0], w[1], time)?;
+                let leg = TimedLeg { from: w[0], to: w[1], ready: time, departure, arrival, criterion };
+                time = arrival;
+                Some(leg)
+            })
+            .collect()
+    }
+
+    fn build_solution(&self, path: Vec<usize>, mut stats: SolverStats, is_exact: bool) -> TimeDependentSolution {
+        let legs = self.simulate(&path).unwrap_or_default();
+        let duration = legs.last().map_or(0, |leg| leg.arrival - self.start_time);
+        let verification = self.graph.verify_route(&path, &RouteMode::Tour { origin: self.origin }, None);
+
+        stats.solve_duration = stats.start_time.map(|t| t.elapsed());
+
+        TimeDependentSolution {
+            is_valid: verification.is_valid() && legs.len() + 1 == path.len(),
+            path,
+            legs,
+            start_time: self.start_time,
+            duration,
+            stats,
+            is_exact,
+            verification,
+        }
+    }
+}
+
+impl std::fmt::Display for TimeDependentSolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "=== TIME-DEPENDENT TSP SOLUTION ===")?;
+        writeln!(f, "Method: {}", if self.is_exact { "Exact DP" } else { "Heuristic" })?;
+        writeln!(f, "Start: {}", self.start_time)?;
+        writeln!(f, "Duration: {}", self.duration)?;
+        writeln!(f, "Path: {}",
+                self.path.iter()
+                    .map(|&i| (i + 1).to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> "))?;
+        writeln!(f, "Valid: {}", if self.is_valid { "Yes" } else { "No" })?;
+        writeln!(f, "{}", self.stats)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Symmetric triangle: 20 per edge in rush hour (criterion 1), 2 at night (criterion 0)
+    fn rush_hour_graph() -> Graph {
+        let mut graph = Graph::new(vec![
+            vec![0, 2, 2],
+            vec![2, 0, 2],
+            vec![2, 2, 0],
+        ]).unwrap();
+        graph.add_weight_matrix("macet", vec![
+            vec![0, 20, 20],
+            vec![20, 0, 20],
+            vec![20, 20, 0],
+        ]).unwrap();
+        graph
+    }
+
+    #[test]
+    fn test_waiting_for_a_faster_slot() -> Result<()> {
+        let slots = vec![TimeSlot { start: 0, criterion: 1 }, TimeSlot { start: 5, criterion: 0 }];
+        let solution = TimeDependentSolver::new(rush_hour_graph(), slots)?.solve()?;
+
+        assert!(solution.is_valid);
+        assert_eq!(solution.path, vec![0, 2, 1, 0]);
+        assert_eq!(solution.duration, 11);
+        assert_eq!((solution.legs[0].ready, solution.legs[0].departure, solution.legs[0].arrival), (0, 5, 7));
+        assert_eq!(solution.legs[0].criterion, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_exact_matches_brute_force() -> Result<()> {
+        let mut graph = Graph::new(vec![
+            vec![0, 10, 15, 20, 12],
+            vec![5,  0,  9, 10,  8],
+            vec![6, 13,  0, 12,  7],
+            vec![8,  8,  9,  0,  6],
+            vec![11, 7,  4,  9,  0],
+        ])?;
+        graph.add_weight_matrix("macet", vec![
+            vec![0, 30,  5, 12, 20],
+            vec![9,  0, 25,  6, 14],
+            vec![7,  8,  0, 30,  5],
+            vec![4, 11,  9,  0, 28],
+            vec![22, 3, 17,  6,  0],
+        ])?;
+        let slots = vec![
+            TimeSlot { start: 0, criterion: 0 },
+            TimeSlot { start: 15, criterion: 1 },
+            TimeSlot { start: 40, criterion: 0 },
+        ];
+        let solver = TimeDependentSolver::new(graph, slots)?.with_start_time(3);
+
+        let mut best = i32::MAX;
+        let mut order = vec![1, 2, 3, 4];
+        for _ in 0..24 {
+            let mut path = vec![0];
+            path.extend(&order);
+            path.push(0);
+            let legs = solver.simulate(&path).unwrap();
+            best = best.min(legs.last().unwrap().arrival - 3);
+            // next permutation
+            let i = (0..3).rev().find(|&i| order[i] < order[i + 1]).unwrap_or(0);
+            let j = (i + 1..4).rev().find(|&j| order[j] > order[i]).unwrap_or(i);
+            order.swap(i, j);
+            order[i + 1..].reverse();
+        }
+
+        let exact = solver.solve()?;
+        assert!(exact.is_valid);
+        assert_eq!(exact.duration, best);
+
+        let heuristic = solver.solve_heuristic()?;
+        assert!(heuristic.is_valid);
+        assert!(heuristic.duration >= exact.duration);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,558 @@
+/// Libraries
+use crate::graph::TourVerification;
+use crate::heuristics::local_search;
+use crate::feasibility::FeasibilityReport;
+use crate::tsp_solver::{RouteMode, SolverStats};
+use crate::{Graph, Result, TSPError};
+use std::collections::HashMap;
+use std::time::Instant;
+
+/// Largest instance handled by the exact label-setting DP
+const MAX_EXACT_SIZE: usize = 16;
+
+/// Service at a city must start within [earliest, latest]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeWindow {
+    pub earliest: i32,
+    pub latest: i32,
+}
+
+/// Time data of one city (arriving before the window opens means waiting)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CityTiming {
+    pub window: Option<TimeWindow>,
+    pub service_time: i32,
+}
+
+/// Arrival, waiting and departure time at one stop of the route
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StopSchedule {
+    pub node: usize,
+    pub arrival: i32,
+    pub wait: i32,
+    pub departure: i32,
+}
+
+/// Result
+#[derive(Debug)]
+pub struct TimeWindowSolution {
+    pub total_cost: i32,
+    pub path: Vec<usize>,
+    pub schedule: Vec<StopSchedule>,
+    pub completion_time: i32,
+    pub stats: SolverStats,
+    pub is_exact: bool,
+    pub is_valid: bool,
+    pub verification: TourVerification,
+}
+
+/// Dominance label of the DP: a partial route ending at `node` after visiting `mask`
+struct Label {
+    cost: i32,
+    departure: i32,
+    node: usize,
+    parent: Option<usize>,
+}
+
+/// TSP with time windows (TSPTW): travel times are the matrix weights, the objective is the
+/// total travel cost, the route starts at the origin when its window opens (time 0 otherwise)
+pub struct TimeWindowSolver {
+    graph: Graph,
+    timings: Vec<CityTiming>,
+    origin: usize,
+}
+
+impl TimeWindowSolver {
+    pub fn new(graph: Graph, timings: Vec<CityTiming>) -> Result<Self> {
+        graph.validate_for_tsp()?;
+
+        if graph.size < 2 {
+            return Err(TSPError::SolverError(
+                "Graf harus memiliki minimal 2 node".to_string()
+            ));
+        }
+
+        if timings.len() != graph.size {
+            return Err(TSPError::InvalidGraph(
+                format!("Jumlah data waktu ({}) tidak sesuai dengan ukuran graf ({})",
+                        timings.len(), graph.size)
+            ));
+        }
+
+        for (i, timing) in timings.iter().enumerate() {
+            if let Some(window) = timing.window.filter(|w| w.earliest > w.latest) {
+                return Err(TSPError::InvalidGraph(
+                    format!("Jendela waktu {} tidak valid: [{}, {}]",
+                            graph.node_names[i], window.earliest, window.latest)
+                ));
+            }
+            if timing.service_time < 0 {
+                return Err(TSPError::InvalidGraph(
+                    format!("Durasi layanan {} tidak boleh negatif", graph.node_names[i])
+                ));
+            }
+        }
+
+        Ok(TimeWindowSolver {
+            graph,
+            timings,
+            origin: 0,
+        })
+    }
+
+    pub fn with_origin(mut self, origin: usize) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Exact DP over (visited set, last city) keeping non-dominated (cost, departure) labels
+    pub fn solve(&self) -> Result<TimeWindowSolution> {
+        self.check_origin()?;
+        FeasibilityReport::analyze(&self.graph, &RouteMode::Tour { origin: self.origin }).into_result()?;
+        if self.graph.size > MAX_EXACT_SIZE {
+            return Err(TSPError::SolverError(
+                format!("Graf terlalu besar (>{} node) untuk DP jendela waktu, gunakan heuristik",
+                        MAX_EXACT_SIZE)
+            ));
+        }
+
+        let mut stats = SolverStats {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        };
+
+        let (cost, path) = self.exact_route(&mut stats)
+            .ok_or_else(|| TSPError::SolverError(self.diagnose_infeasibility()))?;
+
+        Ok(self.build_solution(cost, path, stats, true))
+    }
+
+    /// Deadline-ordered start, then local search minimizing (precedence violations, lateness, cost)
+    pub fn solve_heuristic(&self) -> Result<TimeWindowSolution> {
+        self.check_origin()?;
+        FeasibilityReport::analyze(&self.graph, &RouteMode::Tour { origin: self.origin }).into_result()?;
+
+        let mut stats = SolverStats {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        };
+
+        let mut customers: Vec<usize> = (0..self.graph.size).filter(|&v| v != self.origin).collect();
+        customers.sort_by_key(|&v| {
+            let window = self.timings[v].window;
+            (window.map_or(i32::MAX, |w| w.latest), window.map_or(0, |w| w.earliest), v)
+        });
+
+        let mut route = vec![self.origin];
+        route.extend(customers);
+        route.push(self.origin);
+
+        let movable = 1..route.len() - 1;
+        let evaluate = |candidate: &[usize]| {
+            self.route_cost(candidate).map(|cost| {
+                (self.graph.precedence_violations(candidate).len(), self.simulate(candidate).1, cost)
+            })
+        };
+        let ((violations, lateness, cost), path) = local_search(route, movable, evaluate, &mut stats)
+            .ok_or_else(|| TSPError::SolverError("Heuristik tidak menemukan rute".to_string()))?;
+
+        if violations > 0 || lateness > 0 {
+            return Err(TSPError::SolverError(
+                format!("Heuristik tidak menemukan rute yang layak. {}", self.diagnose_infeasibility())
+            ));
+        }
+
+        Ok(self.build_solution(cost, path, stats, false))
+    }
+
+    fn check_origin(&self) -> Result<()> {
+        if self.origin >= self.graph.size {
+            return Err(TSPError::SolverError(
+                format!("Kota asal {} di luar jangkauan graf", self.origin)
+            ));
+        }
+        Ok(())
+    }
+
+    fn start_time(&self) -> i32 {
+        self.timings[self.origin].window.map_or(0, |w| w.earliest)
+    }
+
+    /// Departure time from `to` after leaving `from` at `departure`, None if its window is missed
+    fn visit(&self, departure: i32, from: usize, to: usize) -> Option<i32> {
+        if !self.graph.can_travel(from, to) {
+            return None;
+        }
+
+        let arrival = departure + self.graph.get_distance(from, to);
+        let timing = &self.timings[to];
+        let service_start = match timing.window {
+            Some(window) if arrival > window.latest => return None,
+            Some(window) => arrival.max(window.earliest),
+            None => arrival,
+        };
+
+        if to == self.origin {
+            Some(arrival)
+        } else {
+            Some(service_start + timing.service_time)
+        }
+    }
+
+    fn exact_route(&self, stats: &mut SolverStats) -> Option<(i32, Vec<usize>)> {
+        let customers: Vec<usize> = (0..self.graph.size).filter(|&v| v != self.origin).collect();
+        let mut labels: Vec<Label> = Vec::new();
+
+        // Precedences: j may only be appended once every required predecessor is on the route
+        let origin_bit = 1u64 << self.origin;
+        if self.graph.predecessors_mask(self.origin) != 0 {
+            return None;
+        }
+        let ready = |mask: u64, j: usize| self.graph.predecessors_mask(j) & !(mask | origin_bit) == 0;
+
+        // Layer k holds every label whose route visited k customers
+        let mut layer: HashMap<(u64, usize), Vec<usize>> = HashMap::new();
+        for &j in customers.iter().filter(|&&j| ready(0, j)) {
+            if let Some(departure) = self.visit(self.start_time(), self.origin, j) {
+                let cost = self.graph.get_distance(self.origin, j);
+                Self::insert_label(&mut labels, &mut layer, (1u64 << j, j), Label {
+                    cost, departure, node: j, parent: None,
+                }, stats);
+            }
+        }
+
+        for _ in 1..customers.len() {
+            let mut next_layer: HashMap<(u64, usize), Vec<usize>> = HashMap::new();
+            let mut keys: Vec<_> = layer.keys().copied().collect();
+            keys.sort_unstable();
+
+            for (mask, node) in keys {
+                for &label_index in &layer[&(mask, node)] {
+                    let (cost, departure) = (labels[label_index].cost, labels[label_index].departure);
+
+                    for &j in customers.iter().filter(|&&j| mask & (1u64 << j) == 0 && ready(mask, j)) {
+                        if let Some(next_departure) = self.visit(departure, node, j) {
+                            Self::insert_label(&mut labels, &mut next_layer, (mask | (1u64 << j), j), Label {
+                                cost: cost + self.graph.get_distance(node, j),
+                                departure: next_departure,
+                                node: j,
+                                parent: Some(label_index),
+                            }, stats);
+                        }
+                    }
+                }
+            }
+            layer = next_layer;
+        }
+
+        // Return to the origin: cheapest cost, earliest return breaks ties
+        let mut best: Option<(i32, i32, usize)> = None;
+        for indices in layer.values() {
+            for &index in indices {
+                let label = &labels[index];
+                if let Some(arrival) = self.visit(label.departure, label.node, self.origin) {
+                    let candidate = (label.cost + self.graph.get_distance(label.node, self.origin), arrival, index);
+                    if best.is_none_or(|b| (candidate.0, candidate.1) < (b.0, b.1)) {
+                        best = Some(candidate);
+                    }
+                }
+            }
+        }
+
+        // Parent pointers give the customers from last to first
+        let (cost, _, index) = best?;
+        let mut customers_visited = Vec::new();
+        let mut current = Some(index);
+        while let Some(i) = current {
+            customers_visited.push(labels[i].node);
+            current = labels[i].parent;
+        }
+        customers_visited.reverse();
+
+        let mut path = vec![self.origin];
+        path.extend(customers_visited);
+        path.push(self.origin);
+
+        Some((cost, path))
+    }
+
+    /// Keep only labels not dominated in both cost and departure time
+    fn insert_label(
+        labels: &mut Vec<Label>,
+        layer: &mut HashMap<(u64, usize), Vec<usize>>,
+        key: (u64, usize),
+        label: Label,
+        stats: &mut SolverStats,
+    ) {
+        let bucket = layer.entry(key).or_default();
+        if bucket.iter().any(|&i| labels[i].cost <= label.cost && labels[i].departure <= label.departure) {
+            stats.cache_hits += 1;
+            return;
+        }
+
+        bucket.retain(|&i| !(label.cost <= labels[i].cost && label.departure <= labels[i].departure));
+        bucket.push(labels.len());
+        labels.push(label);
+        stats.states_computed += 1;
+    }
+
+    fn route_cost(&self, path: &[usize]) -> Option<i32> {
+        path.windows(2).try_fold(0, |total, w| {
+            self.graph.can_travel(w[0], w[1]).then(|| total + self.graph.get_distance(w[0], w[1]))
+        })
+    }
+
+    /// Schedule of a closed route (service starts as early as allowed) and its total lateness
+    fn simulate(&self, path: &[usize]) -> (Vec<StopSchedule>, i32) {
+        let mut schedule = Vec::with_capacity(path.len());
+        let mut lateness = 0;
+        let mut time = self.start_time();
+
+        for (position, &node) in path.iter().enumerate() {
+            if position == 0 {
+                schedule.push(StopSchedule { node, arrival: time, wait: 0, departure: time });
+                continue;
+            }
+
+            let arrival = time + self.graph.get_distance(path[position - 1], node);
+            let timing = &self.timings[node];
+            let service_start = timing.window.map_or(arrival, |w| arrival.max(w.earliest));
+            if let Some(window) = timing.window {
+                lateness += (service_start - window.latest).max(0);
+            }
+
+            let is_return = position == path.len() - 1;
+            time = if is_return { arrival } else { service_start + timing.service_time };
+            schedule.push(StopSchedule {
+                node,
+                arrival,
+                wait: if is_return { 0 } else { service_start - arrival },
+                departure: time,
+            });
+        }
+
+        (schedule, lateness)
+    }
+
+    fn build_solution(&self, cost: i32, path: Vec<usize>, mut stats: SolverStats, is_exact: bool) -> TimeWindowSolution {
+        let (schedule, lateness) = self.simulate(&path);
+        let verification = self.graph.verify_tour(&path, Some(cost));
+        stats.solve_duration = stats.start_time.map(|t| t.elapsed());
+
+        TimeWindowSolution {
+            total_cost: cost,
+            completion_time: schedule.last().map_or(0, |stop| stop.arrival),
+            is_valid: verification.is_valid() && lateness == 0 && path[0] == self.origin,
+            path,
+            schedule,
+            stats,
+            is_exact,
+            verification,
+        }
+    }
+
+    /// Plain explanation of why no route meets every window, naming the binding window(s)
+    fn diagnose_infeasibility(&self) -> String {
+        let names = &self.graph.node_names;
+        let start = self.start_time();
+        let describe = |v: usize, w: TimeWindow| format!("{} [{}, {}]", names[v], w.earliest, w.latest);
+
+        // The origin comes first, so it cannot have a required predecessor
+        if let Some(&(before, _)) = self.graph.precedences.iter().find(|&&(_, after)| after == self.origin) {
+            return format!(
+                "Tidak ada rute yang layak: {} harus dikunjungi sebelum kota asal {}",
+                names[before], names[self.origin]
+            );
+        }
+
+        // A window that closes before the city can be reached directly from the origin
+        for v in (0..self.graph.size).filter(|&v| v != self.origin) {
+            if let (Some(window), Some(distance)) = (self.timings[v].window, self.graph.edge_weight(self.origin, v)) {
+                let earliest_arrival = start + distance;
+                if earliest_arrival > window.latest {
+                    return format!(
+                        "Tidak ada rute yang layak: jendela waktu {} sudah tutup, kedatangan paling awal dari {} adalah {}",
+                        describe(v, window), names[self.origin], earliest_arrival
+                    );
+                }
+            }
+        }
+
+        // The origin's own window closes before some city can be served and left
+        if let Some(window) = self.timings[self.origin].window {
+            for v in (0..self.graph.size).filter(|&v| v != self.origin) {
+                let (Some(departure), Some(distance)) = (self.visit(start, self.origin, v), self.graph.edge_weight(v, self.origin)) else {
+                    continue;
+                };
+                let earliest_return = departure + distance;
+                if earliest_return > window.latest {
+                    return format!(
+                        "Tidak ada rute yang layak: jendela waktu {} tutup sebelum bisa kembali dari {} (paling awal {})",
+                        describe(self.origin, window), names[v], earliest_return
+                    );
+                }
+            }
+        }
+
+        // Windows whose removal alone makes the instance feasible
+        let mut binding = Vec::new();
+        if self.graph.size <= MAX_EXACT_SIZE {
+            for v in 0..self.graph.size {
+                let Some(window) = self.timings[v].window else {
+                    continue;
+                };
+
+                let mut relaxed = self.timings.clone();
+                relaxed[v].window = None;
+                let solver = TimeWindowSolver {
+                    graph: self.graph.clone(),
+                    timings: relaxed,
+                    origin: self.origin,
+                };
+                if solver.exact_route(&mut SolverStats::default()).is_some() {
+                    binding.push(describe(v, window));
+                }
+            }
+        }
+
+        if binding.is_empty() {
+            "Tidak ada rute yang layak: kombinasi jendela waktu saling bertentangan".to_string()
+        } else {
+            format!(
+                "Tidak ada rute yang layak: jendela waktu pengikat {} (tanpa jendela tersebut rute layak ditemukan)",
+                binding.join(", ")
+            )
+        }
+    }
+}
+
+impl std::fmt::Display for TimeWindowSolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "=== TSP TIME WINDOW SOLUTION ===")?;
+        writeln!(f, "Method: {}", if self.is_exact { "Exact DP" } else { "Heuristic" })?;
+        writeln!(f, "Total Cost: {}", self.total_cost)?;
+        writeln!(f, "Completion Time: {}", self.completion_time)?;
+        writeln!(f, "Path: {}",
+                self.path.iter()
+                    .map(|&i| (i + 1).to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> "))?;
+        writeln!(f, "Valid: {}", if self.is_valid { "Yes" } else { "No" })?;
+        writeln!(f, "{}", self.stats)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document_graph() -> Graph {
+        let matrix = vec![
+            vec![0, 10, 15, 20],
+            vec![5,  0,  9, 10],
+            vec![6, 13,  0, 12],
+            vec![8,  8,  9,  0],
+        ];
+        let names = vec!["A", "B", "C", "D"].into_iter().map(String::from).collect();
+        Graph::with_node_names(matrix, names).unwrap()
+    }
+
+    fn window(earliest: i32, latest: i32) -> CityTiming {
+        CityTiming { window: Some(TimeWindow { earliest, latest }), service_time: 0 }
+    }
+
+    #[test]
+    fn test_deadline_changes_the_route() -> Result<()> {
+        // C must be served by time 16, so it has to be the first stop
+        let mut timings = vec![CityTiming::default(); 4];
+        timings[2] = window(0, 16);
+
+        let solver = TimeWindowSolver::new(document_graph(), timings)?;
+        let exact = solver.solve()?;
+        assert!(exact.is_valid);
+        assert_eq!(exact.total_cost, 40);
+        assert_eq!(exact.path, vec![0, 2, 3, 1, 0]);
+        assert_eq!(exact.schedule[1], StopSchedule { node: 2, arrival: 15, wait: 0, departure: 15 });
+
+        let heuristic = solver.solve_heuristic()?;
+        assert!(heuristic.is_valid);
+        assert!(heuristic.total_cost >= exact.total_cost);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_waiting_and_service_times() -> Result<()> {
+        let mut timings = vec![CityTiming::default(); 4];
+        timings[1] = CityTiming { window: Some(TimeWindow { earliest: 20, latest: 30 }), service_time: 2 };
+
+        let solver = TimeWindowSolver::new(document_graph(), timings)?;
+        let (schedule, lateness) = solver.simulate(&[0, 1, 3, 2, 0]);
+
+        assert_eq!(lateness, 0);
+        assert_eq!(schedule[1], StopSchedule { node: 1, arrival: 10, wait: 10, departure: 22 });
+        assert_eq!(schedule[4].arrival, 22 + 10 + 9 + 6);
+
+        let exact = solver.solve()?;
+        assert!(exact.is_valid);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_infeasible_names_binding_window() -> Result<()> {
+        let mut timings = vec![CityTiming::default(); 4];
+        timings[2] = window(0, 10);
+
+        let solver = TimeWindowSolver::new(document_graph(), timings)?;
+        let message = solver.solve().unwrap_err().to_string();
+        assert!(message.contains("C [0, 10]"), "{}", message);
+
+        // Each window alone is fine, together they conflict
+        let mut timings = vec![CityTiming::default(); 4];
+        timings[1] = window(0, 10);
+        timings[2] = window(0, 15);
+
+        let solver = TimeWindowSolver::new(document_graph(), timings)?;
+        let message = solver.solve().unwrap_err().to_string();
+        assert!(message.contains("B [0, 10]") && message.contains("C [0, 15]"), "{}", message);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_precedence_with_windows() -> Result<()> {
+        // D before C pushes C past its deadline
+        let mut graph = document_graph();
+        graph.add_precedence(3, 2)?;
+        let mut timings = vec![CityTiming::default(); 4];
+        timings[2] = window(0, 16);
+
+        let solver = TimeWindowSolver::new(graph, timings)?;
+        let message = solver.solve().unwrap_err().to_string();
+        assert!(message.contains("C [0, 16]"), "{}", message);
+        assert!(solver.solve_heuristic().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_forbidden_edge() -> Result<()> {
+        // Without 1 -> 3 the 35 tour 0 -> 1 -> 3 -> 2 -> 0 is gone
+        let mut graph = document_graph();
+        graph.forbid_edge(1, 3)?;
+
+        let solver = TimeWindowSolver::new(graph, vec![CityTiming::default(); 4])?;
+        let exact = solver.solve()?;
+        assert!(exact.is_valid);
+        assert_eq!(exact.total_cost, 39);
+        assert_eq!(exact.path, vec![0, 1, 2, 3, 0]);
+
+        let heuristic = solver.solve_heuristic()?;
+        assert!(heuristic.is_valid);
+        assert!(!heuristic.path.windows(2).any(|w| w == [1, 3]));
+
+        Ok(())
+    }
+}
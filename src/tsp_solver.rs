@@ -1,18 +1,246 @@
 /// Libraries
+use crate::feasibility::FeasibilityReport;
+use crate::graph::{TourIssue, TourVerification};
+use crate::pickup_delivery::LoadProfile;
+use crate::sensitivity::SensitivityReport;
 use crate::{Graph, Result, TSPError};
 use colored::*;
+use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
 use std::time::{Duration, Instant};
 
 pub struct TSPSolver {
     graph: Graph,
-    memo: HashMap<(usize, u64), (i32, Option<usize>)>, // (cost, next_node)
+    memo: HashMap<(usize, u64), (RouteScore, Vec<usize>)>, // (score, tied next nodes)
+    final_choices: Vec<usize>, // tied first nodes after the start
+    constraints: EdgeConstraints,
+    load_profile: Option<LoadProfile>,
+    mode: RouteMode,
+    objective: Objective,
     verbose: bool,
     stats: SolverStats,
 }
 
+/// Route shape optimized by the solver
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RouteMode {
+    /// Closed tour starting and ending at `origin`
+    Tour { origin: usize },
+    /// Open Hamiltonian path (no return), `start: None` lets the solver pick the start
+    OpenPath { start: Option<usize> },
+    /// Hamiltonian path from a fixed start to a fixed (different) end
+    PathBetween { start: usize, end: usize },
+}
+
+impl Default for RouteMode {
+    fn default() -> Self {
+        RouteMode::Tour { origin: 0 }
+    }
+}
+
+impl RouteMode {
+    pub(crate) fn validate(&self, size: usize) -> Result<()> {
+        for node in self.start().into_iter().chain(self.end()) {
+            if node >= size {
+                return Err(TSPError::SolverError(
+                    format!("Kota {} di luar jangkauan graf", node)
+                ));
+            }
+        }
+
+        if let RouteMode::PathBetween { start, end } = self {
+            if start == end {
+                return Err(TSPError::SolverError(
+                    "Kota awal dan tujuan harus berbeda (gunakan mode tour)".to_string()
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn is_closed(&self) -> bool {
+        matches!(self, RouteMode::Tour { .. })
+    }
+
+    /// Node the route is fixed to start at
+    pub fn start(&self) -> Option<usize> {
+        match self {
+            RouteMode::Tour { origin } => Some(*origin),
+            RouteMode::OpenPath { start } => *start,
+            RouteMode::PathBetween { start, .. } => Some(*start),
+        }
+    }
+
+    /// Node the route is fixed to end at
+    pub fn end(&self) -> Option<usize> {
+        match self {
+            RouteMode::Tour { origin } => Some(*origin),
+            RouteMode::OpenPath { .. } => None,
+            RouteMode::PathBetween { end, .. } => Some(*end),
+        }
+    }
+}
+
+/// Value the DP optimizes over the candidate routes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Objective {
+    /// Shortest total length
+    #[default]
+    MinCost,
+    /// Smallest longest edge (bottleneck TSP)
+    Bottleneck,
+    /// Longest total length (max-TSP)
+    MaxCost,
+    /// Shortest total length, ties broken by the smallest longest edge
+    CostThenBottleneck,
+}
+
+impl Objective {
+    /// Comparison key, smaller is better
+    pub fn key(&self, score: RouteScore) -> (i32, i32) {
+        match self {
+            Objective::MinCost => (score.cost, 0),
+            Objective::Bottleneck => (score.bottleneck, 0),
+            Objective::MaxCost => (-score.cost, 0),
+            Objective::CostThenBottleneck => (score.cost, score.bottleneck),
+        }
+    }
+
+    /// Value reported for a route (the total length, or the longest edge for bottleneck)
+    pub fn value(&self, score: RouteScore) -> i32 {
+        match self {
+            Objective::Bottleneck => score.bottleneck,
+            _ => score.cost,
+        }
+    }
+
+    pub fn uses_bottleneck(&self) -> bool {
+        matches!(self, Objective::Bottleneck | Objective::CostThenBottleneck)
+    }
+}
+
+impl std::fmt::Display for Objective {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Objective::MinCost => write!(f, "Min Cost"),
+            Objective::Bottleneck => write!(f, "Min Bottleneck"),
+            Objective::MaxCost => write!(f, "Max Cost"),
+            Objective::CostThenBottleneck => write!(f, "Min Cost, then Min Bottleneck"),
+        }
+    }
+}
+
+/// Total length and longest edge of a (partial) route
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RouteScore {
+    pub cost: i32,
+    pub bottleneck: i32,
+}
+
+/// Empty route: no edge yet, so any edge (even a negative one) becomes the longest
+impl Default for RouteScore {
+    fn default() -> Self {
+        RouteScore { cost: 0, bottleneck: i32::MIN }
+    }
+}
+
+impl RouteScore {
+    /// The same route preceded by an edge of `weight`
+    pub fn extend(self, weight: i32) -> Self {
+        RouteScore {
+            cost: self.cost + weight,
+            bottleneck: self.bottleneck.max(weight),
+        }
+    }
+
+    /// Score of a whole path, None when it uses a missing edge
+    pub fn of_path(graph: &Graph, path: &[usize]) -> Option<Self> {
+        path.windows(2).try_fold(RouteScore::default(), |score, w| {
+            graph.has_edge(w[0], w[1]).then(|| score.extend(graph.get_distance(w[0], w[1])))
+        })
+    }
+}
+
+/// Keep the best-keyed successors of a DP state; on ties the score follows the smallest node,
+/// the one `reconstruct_path` takes
+fn offer(best: &mut Option<(RouteScore, Vec<usize>)>, objective: Objective, node: usize, score: RouteScore) {
+    let Some((current, nodes)) = best else {
+        *best = Some((score, vec![node]));
+        return;
+    };
+
+    match objective.key(score).cmp(&objective.key(*current)) {
+        Ordering::Less => *best = Some((score, vec![node])),
+        Ordering::Equal => {
+            if nodes.iter().all(|&other| node < other) {
+                *current = score;
+            }
+            nodes.push(node);
+        }
+        Ordering::Greater => {}
+    }
+}
+
+/// Arc constraints (required/forbidden) restricting which tours the DP may build
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EdgeConstraints {
+    pub required: Vec<(usize, usize)>,
+    pub forbidden: HashSet<(usize, usize)>,
+}
+
+impl EdgeConstraints {
+    pub fn is_empty(&self) -> bool {
+        self.required.is_empty() && self.forbidden.is_empty()
+    }
+
+    /// Add the arcs of `other` (duplicates are skipped)
+    pub fn extend(&mut self, other: &EdgeConstraints) {
+        for &edge in &other.required {
+            if !self.required.contains(&edge) {
+                self.required.push(edge);
+            }
+        }
+        self.forbidden.extend(other.forbidden.iter().copied());
+    }
+
+    /// Required arcs missing from `path`
+    pub fn missing_required(&self, path: &[usize]) -> Vec<(usize, usize)> {
+        self.required
+            .iter()
+            .copied()
+            .filter(|&(from, to)| !path.windows(2).any(|w| w[0] == from && w[1] == to))
+            .collect()
+    }
+
+    /// Forbidden arcs used by `path`, in route order
+    pub fn forbidden_used(&self, path: &[usize]) -> Vec<(usize, usize)> {
+        path.windows(2)
+            .map(|w| (w[0], w[1]))
+            .filter(|edge| self.forbidden.contains(edge))
+            .collect()
+    }
+
+    /// A required arc (a, b) fixes both the successor of a and the predecessor of b
+    pub fn allows(&self, from: usize, to: usize) -> bool {
+        if self.forbidden.contains(&(from, to)) {
+            return false;
+        }
+        self.required.iter().all(|&(a, b)| (a == from) == (b == to))
+    }
+
+    /// Open paths: the last node has no successor
+    pub fn has_required_successor(&self, node: usize) -> bool {
+        self.required.iter().any(|&(a, _)| a == node)
+    }
+
+    /// Open paths with a free start: the first node has no predecessor
+    pub fn has_required_predecessor(&self, node: usize) -> bool {
+        self.required.iter().any(|&(_, b)| b == node)
+    }
+}
+
 /// Stats
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct SolverStats {
     pub start_time: Option<Instant>,
     pub solve_duration: Option<Duration>,
@@ -20,15 +248,23 @@ pub struct SolverStats {
     pub cache_hits: usize,
     pub cache_misses: usize,
     pub max_memory_states: usize,
+    pub states_recomputed: usize, // incremental re-solve only
+    pub states_reused: usize,     // incremental re-solve only
 }
 
 /// Result
 #[derive(Debug)]
 pub struct TSPSolution {
-    pub optimal_cost: i32,
+    pub optimal_cost: i32, // total length, whatever the objective
+    pub bottleneck: i32,   // longest edge of the route
+    pub objective: Objective,
     pub optimal_path: Vec<usize>,
     pub stats: SolverStats,
     pub is_valid: bool,
+    pub verification: TourVerification,
+    pub optimal_tour_count: u64,
+    pub mode: RouteMode,
+    pub origin: usize, // first city of the route (the chosen origin for tours)
 }
 
 impl TSPSolver {
@@ -48,8 +284,13 @@ impl TSPSolver {
         }
 
         Ok(TSPSolver {
+            constraints: graph.edge_constraints.clone(),
             graph,
             memo: HashMap::new(),
+            final_choices: Vec::new(),
+            load_profile: None,
+            mode: RouteMode::default(),
+            objective: Objective::default(),
             verbose: false,
             stats: SolverStats::default(),
         })
@@ -60,9 +301,29 @@ impl TSPSolver {
         self
     }
 
+    pub fn with_mode(mut self, mode: RouteMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn with_objective(mut self, objective: Objective) -> Self {
+        self.objective = objective;
+        self
+    }
+
+    /// Extra arc constraints on top of the graph's own
+    pub fn with_edge_constraints(mut self, constraints: EdgeConstraints) -> Self {
+        self.constraints.extend(&constraints);
+        self
+    }
+
+    pub(crate) fn with_load_profile(mut self, profile: LoadProfile) -> Self {
+        self.load_profile = Some(profile);
+        self
+    }
+
     pub fn solve(&mut self) -> Result<TSPSolution> {
         self.stats.start_time = Some(Instant::now());
-        self.memo.clear();
         self.stats = SolverStats {
             start_time: Some(Instant::now()),
             ..Default::default()
@@ -78,50 +339,223 @@ impl TSPSolver {
             println!();
         }
 
-        // Phase 1: Basis - f(i, ∅) = c_i,0 for i = 1, 2, ..., n-1
-        self.compute_base_cases()?;
+        let optimal_score = self.run_dp()?.ok_or_else(|| match self.constraints.is_empty() {
+            true => TSPError::SolverError("Tidak dapat menemukan solusi optimal".to_string()),
+            false => TSPError::SolverError(
+                "Tidak ada rute yang memenuhi batasan edge wajib/terlarang".to_string()
+            ),
+        })?;
 
-        // Phase 2-n: Iterate for any subset size from 1 to n-1
-        for subset_size in 1..self.graph.size - 1 {
-            self.compute_subset_size(subset_size)?;
+        self.finish_solution(optimal_score)
+    }
+
+    /// Change edge weights `(from, to, weight)` on a solved instance and re-solve,
+    /// recomputing only the DP states whose value depends on a changed edge (a missing edge is added)
+    pub fn update_edge_weights(&mut self, changes: &[(usize, usize, i32)]) -> Result<TSPSolution> {
+        if self.final_choices.is_empty() {
+            return Err(TSPError::SolverError("Solver belum dijalankan".to_string()));
         }
 
-        // Final Phase: Compute f(0, {1, 2, ..., n-1})
-        let (optimal_cost, first_next) = self.compute_final_result()?;
+        for &(from, to, _) in changes {
+            if from >= self.graph.size || to >= self.graph.size || from == to {
+                return Err(TSPError::InvalidGraph(
+                    format!("Edge ({}, {}) tidak valid", from, to)
+                ));
+            }
+        }
+
+        self.stats = SolverStats {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        };
 
+        let mut changed_edges = HashSet::new();
+        for &(from, to, weight) in changes {
+            if self.graph.edge_weight(from, to) != Some(weight) {
+                self.graph.set_edge(from, to, weight)?;
+                changed_edges.insert((from, to));
+            }
+        }
+
+        // States are visited by increasing subset size, so every dependency is already up to date
+        let mut changed_states: HashSet<(usize, u64)> = HashSet::new();
+        let inner_nodes = self.inner_nodes();
+        for subset_size in 0..inner_nodes.len() {
+            for subset in self.generate_subsets(&inner_nodes, subset_size) {
+                let mask = self.set_to_mask(&subset);
+
+                for &i in &inner_nodes {
+                    if subset.contains(&i) {
+                        continue;
+                    }
+
+                    let is_affected = if subset.is_empty() {
+                        self.mode.end().is_some_and(|end| changed_edges.contains(&(i, end)))
+                    } else {
+                        subset.iter().any(|&j| {
+                            changed_edges.contains(&(i, j))
+                                || changed_states.contains(&(j, mask & !(1u64 << j)))
+                        })
+                    };
+
+                    if !is_affected {
+                        self.stats.states_reused += 1;
+                        continue;
+                    }
+
+                    let result = if subset.is_empty() {
+                        self.base_cost(i).map(|score| (score, Vec::new()))
+                    } else {
+                        self.compute_dp_state(i, &subset)?
+                    };
+                    self.stats.states_recomputed += 1;
+                    self.stats.states_computed += 1;
+
+                    let previous = match result {
+                        Some(value) => self.memo.insert((i, mask), value),
+                        None => self.memo.remove(&(i, mask)),
+                    };
+                    if previous.as_ref() != self.memo.get(&(i, mask)) {
+                        changed_states.insert((i, mask));
+                    }
+                }
+            }
+        }
+
+        let (optimal_score, first_choices) = self.compute_final_result()?;
+        self.final_choices = first_choices;
+        if self.final_choices.is_empty() {
+            return Err(TSPError::SolverError("Tidak dapat menemukan solusi optimal".to_string()));
+        }
+
+        self.finish_solution(optimal_score)
+    }
+
+    fn finish_solution(&mut self, optimal_score: RouteScore) -> Result<TSPSolution> {
         // Reconstruct
-        let optimal_path = self.reconstruct_path(first_next)?;
+        let optimal_path = self.reconstruct_path(self.final_choices[0])?;
+        let optimal_tour_count = self.count_optimal_tours()?;
 
         // Validate
-        let is_valid = self.validate_solution(&optimal_path, optimal_cost)?;
+        let verification = self.validate_solution(&optimal_path, optimal_score);
+        let is_valid = verification.is_valid();
 
         self.stats.solve_duration = self.stats.start_time.map(|t| t.elapsed());
         self.stats.max_memory_states = self.memo.len();
 
         Ok(TSPSolution {
-            optimal_cost,
+            optimal_cost: optimal_score.cost,
+            bottleneck: optimal_score.bottleneck,
+            objective: self.objective,
+            origin: optimal_path[0],
             optimal_path,
             stats: std::mem::take(&mut self.stats),
             is_valid,
+            verification,
+            optimal_tour_count,
+            mode: self.mode,
         })
     }
 
+    /// Best tour only (no stats or validation), None when the constraints admit no tour
+    pub(crate) fn solve_best_tour(&mut self) -> Result<Option<(i32, Vec<usize>)>> {
+        match self.run_dp()? {
+            Some(score) => Ok(Some((score.cost, self.reconstruct_path(self.final_choices[0])?))),
+            None => Ok(None),
+        }
+    }
+
+    fn run_dp(&mut self) -> Result<Option<RouteScore>> {
+        self.mode.validate(self.graph.size)?;
+        FeasibilityReport::analyze(&self.graph, &self.mode).into_result()?;
+        self.memo.clear();
+
+        // Phase 1: Basis - f(i, ∅) = c_i,0 for i = 1, 2, ..., n-1 (0 for open paths)
+        self.compute_base_cases()?;
+
+        // Phase 2-n: Iterate for any subset size from 1 to n-1
+        for subset_size in 1..self.inner_nodes().len() {
+            self.compute_subset_size(subset_size)?;
+        }
+
+        // Final Phase: Compute f(0, {1, 2, ..., n-1})
+        let (optimal_score, first_choices) = self.compute_final_result()?;
+        self.final_choices = first_choices;
+
+        if self.final_choices.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(optimal_score))
+        }
+    }
+
+    /// The edge exists and the edge constraints allow it
+    fn can_move(&self, from: usize, to: usize) -> bool {
+        self.graph.has_edge(from, to) && self.constraints.allows(from, to)
+    }
+
+    /// Nodes visited between the fixed endpoints (every node for a free open path)
+    fn inner_nodes(&self) -> Vec<usize> {
+        (0..self.graph.size)
+            .filter(|&node| Some(node) != self.mode.start() && Some(node) != self.mode.end())
+            .collect()
+    }
+
+    /// f(i, ∅): cost of the last leg from i (nothing left once i is the final node of an open path)
+    fn base_cost(&self, i: usize) -> Option<RouteScore> {
+        if !self.is_feasible_state(i, 0) {
+            return None;
+        }
+
+        match self.mode.end() {
+            Some(end) => self.can_move(i, end)
+                .then(|| RouteScore::default().extend(self.graph.get_distance(i, end))),
+            None => (!self.constraints.has_required_successor(i)).then(RouteScore::default),
+        }
+    }
+
+    /// Suffix-closure pruning: no node still to come (`later`, plus the fixed end of a path) may be
+    /// required before i, and the load on board after i must fit the vehicle
+    fn is_feasible_state(&self, i: usize, later: u64) -> bool {
+        let end_mask = match self.mode.end() {
+            Some(end) if !self.mode.is_closed() => 1u64 << end,
+            _ => 0,
+        };
+        if self.graph.predecessors_mask(i) & (later | end_mask) != 0 {
+            return false;
+        }
+
+        let visited = !(later | end_mask);
+        self.load_profile
+            .as_ref()
+            .is_none_or(|profile| profile.fits(|node| visited & (1u64 << node) != 0))
+    }
+
     /// Compute base cases: f(i, ∅) = c_i,0
     fn compute_base_cases(&mut self) -> Result<()> {
         if self.verbose {
-            println!("{}", "Tahap 1 - Basis: f(i, ∅) = c_i,0".yellow().bold());
+            let basis = if self.mode.is_closed() { "c_i,0" } else { "0" };
+            println!("{}", format!("Tahap 1 - Basis: f(i, ∅) = {}", basis).yellow().bold());
         }
 
-        for i in 1..self.graph.size {
-            let cost = self.graph.get_distance(i, 0);
-            self.memo.insert((i, 0), (cost, None));
+        for i in self.inner_nodes() {
+            let Some(score) = self.base_cost(i) else {
+                continue;
+            };
+
+            self.memo.insert((i, 0), (score, Vec::new()));
             self.stats.states_computed += 1;
 
             if self.verbose {
-                println!("f({}, ∅) = {} (jarak dari {} ke {})", 
-                        i + 1, cost, 
-                        self.graph.node_names[i].bright_cyan(),
-                        self.graph.node_names[0].bright_cyan());
+                match self.mode.end() {
+                    Some(end) => println!("f({}, ∅) = {} (jarak dari {} ke {})", 
+                            i + 1, score.cost, 
+                            self.graph.node_names[i].bright_cyan(),
+                            self.graph.node_names[end].bright_cyan()),
+                    None => println!("f({}, ∅) = 0 ({} sebagai kota terakhir)", 
+                            i + 1, 
+                            self.graph.node_names[i].bright_cyan()),
+                }
             }
         }
 
@@ -138,29 +572,29 @@ impl TSPSolver {
                     .yellow().bold());
         }
 
-        let available_nodes: Vec<usize> = (1..self.graph.size).collect();
-        let subsets = self.generate_subsets(&available_nodes, subset_size);
+        let inner_nodes = self.inner_nodes();
+        let subsets = self.generate_subsets(&inner_nodes, subset_size);
 
         for subset in subsets {
             let mask = self.set_to_mask(&subset);
             
-            for i in 1..self.graph.size {
+            for &i in &inner_nodes {
                 if subset.contains(&i) {
                     continue;
                 }
 
                 let result = self.compute_dp_state(i, &subset)?;
-                if let Some((cost, next)) = result {
-                    self.memo.insert((i, mask), (cost, next));
-                    self.stats.states_computed += 1;
-
+                if let Some((score, next)) = result {
                     if self.verbose {
                         println!("f({}, {:?}) = {} (next: {:?})", 
                                i + 1, 
                                subset.iter().map(|&x| x + 1).collect::<Vec<_>>(),
-                               cost,
-                               next.map(|x| x + 1));
+                               self.objective.value(score),
+                               next.iter().map(|&x| x + 1).collect::<Vec<_>>());
                     }
+
+                    self.memo.insert((i, mask), (score, next));
+                    self.stats.states_computed += 1;
                 }
             }
         }
@@ -171,102 +605,135 @@ impl TSPSolver {
         Ok(())
     }
 
-    /// Compute single DP state: f(i, S) = min{c_ij + f(j, S - {j})}
-    fn compute_dp_state(&mut self, i: usize, subset: &HashSet<usize>) -> Result<Option<(i32, Option<usize>)>> {
-        let mut min_cost = i32::MAX;
-        let mut best_next = None;
+    /// Compute single DP state: f(i, S) = best{c_ij ⊕ f(j, S - {j})} under the objective
+    /// (⊕ is + for the length and max for the bottleneck), keeping every tied j
+    fn compute_dp_state(&mut self, i: usize, subset: &HashSet<usize>) -> Result<Option<(RouteScore, Vec<usize>)>> {
+        if !self.is_feasible_state(i, self.set_to_mask(subset)) {
+            return Ok(None);
+        }
+
+        let mut best = None;
 
         for &j in subset {
+            if !self.can_move(i, j) {
+                continue;
+            }
+
             let mut new_subset = subset.clone();
             new_subset.remove(&j);
             let new_mask = self.set_to_mask(&new_subset);
 
-            let prev_result = if let Some(&result) = self.memo.get(&(j, new_mask)) {
+            let prev_score = if let Some(&(score, _)) = self.memo.get(&(j, new_mask)) {
                 self.stats.cache_hits += 1;
-                result
+                score
             } else {
+                // Unreachable state (pruned by the edge constraints)
                 self.stats.cache_misses += 1;
-                return Ok(None);
+                continue;
             };
 
-            let total_cost = self.graph.get_distance(i, j) + prev_result.0;
-            if total_cost < min_cost {
-                min_cost = total_cost;
-                best_next = Some(j);
-            }
+            offer(&mut best, self.objective, j, prev_score.extend(self.graph.get_distance(i, j)));
         }
 
-        if min_cost == i32::MAX {
-            Ok(None)
-        } else {
-            Ok(Some((min_cost, best_next)))
-        }
+        Ok(best.map(|(score, mut next)| {
+            next.sort_unstable();
+            (score, next)
+        }))
     }
 
-    /// Compute final: f(0, {1, 2, ..., n-1})
-    fn compute_final_result(&mut self) -> Result<(i32, usize)> {
-        let full_set: HashSet<usize> = (1..self.graph.size).collect();
+    /// Compute final: f(0, {1, 2, ..., n-1}), keeping every tied first node
+    fn compute_final_result(&mut self) -> Result<(RouteScore, Vec<usize>)> {
+        let inner_nodes = self.inner_nodes();
+        let full_set: HashSet<usize> = inner_nodes.iter().copied().collect();
         
-        let mut min_cost = i32::MAX;
-        let mut first_next = None;
+        let mut best = None;
 
         if self.verbose {
-            println!("{}", 
-                    format!("Tahap Final - Menghitung f(1, {{2,3,...,{}}}):", self.graph.size)
-                    .green().bold());
+            match self.mode.start() {
+                Some(start) if self.mode.is_closed() && start == 0 => println!("{}", 
+                        format!("Tahap Final - Menghitung f(1, {{2,3,...,{}}}):", self.graph.size)
+                        .green().bold()),
+                Some(start) => println!("{}", 
+                        format!("Tahap Final - Memilih kota setelah {}:", self.graph.node_names[start])
+                        .green().bold()),
+                None => println!("{}", "Tahap Final - Memilih kota pertama:".green().bold()),
+            }
+        }
+
+        // A fixed start comes first, so it cannot have a required predecessor
+        if self.mode.start().is_some_and(|start| self.graph.predecessors_mask(start) != 0) {
+            return Ok((RouteScore::default(), Vec::new()));
         }
 
-        for k in 1..self.graph.size {
+        // Only the fixed endpoints remain (2-city path): the direct edge is the answer
+        if let (true, Some(start), Some(end)) = (inner_nodes.is_empty(), self.mode.start(), self.mode.end()) {
+            if start != end && self.can_move(start, end) {
+                let score = RouteScore::default().extend(self.graph.get_distance(start, end));
+                offer(&mut best, self.objective, end, score);
+            }
+        }
+
+        for &k in &inner_nodes {
+            // Weight of entering k first (free start: k has no predecessor)
+            let entry_weight = match self.mode.start() {
+                Some(start) if self.can_move(start, k) => Some(self.graph.get_distance(start, k)),
+                Some(_) => continue,
+                None if self.constraints.has_required_predecessor(k) => continue,
+                None => None,
+            };
+
             let mut reduced_set = full_set.clone();
             reduced_set.remove(&k);
             let reduced_mask = self.set_to_mask(&reduced_set);
 
-            if let Some(&(prev_cost, _)) = self.memo.get(&(k, reduced_mask)) {
-                let total_cost = self.graph.get_distance(0, k) + prev_cost;
+            if let Some(&(prev_score, _)) = self.memo.get(&(k, reduced_mask)) {
+                let total = entry_weight.map_or(prev_score, |weight| prev_score.extend(weight));
                 
                 if self.verbose {
-                    println!("  c_1,{} + f({}, subset) = {} + {} = {}", 
-                           k + 1, k + 1, self.graph.get_distance(0, k), prev_cost, total_cost);
+                    match (self.mode.start(), entry_weight) {
+                        (Some(start), Some(weight)) => println!("  c_{},{} ⊕ f({}, subset) = {} ⊕ {} = {}", 
+                               start + 1, k + 1, k + 1, weight, 
+                               self.objective.value(prev_score), self.objective.value(total)),
+                        _ => println!("  f({}, subset) = {}", k + 1, self.objective.value(total)),
+                    }
                 }
                 
-                if total_cost < min_cost {
-                    min_cost = total_cost;
-                    first_next = Some(k);
-                }
+                offer(&mut best, self.objective, k, total);
                 self.stats.cache_hits += 1;
             } else {
                 self.stats.cache_misses += 1;
             }
         }
 
-        let first_next = first_next.ok_or_else(|| 
-            TSPError::SolverError("Tidak dapat menemukan solusi optimal".to_string())
-        )?;
-
-        if self.verbose {
-            println!("\n{} {}", "Biaya minimum tour:".green().bold(), 
-                    min_cost.to_string().bright_green());
+        let (score, first_next) = best.unwrap_or_default();
+        if self.verbose && !first_next.is_empty() {
+            println!("\n{} {}", "Nilai objektif optimal:".green().bold(), 
+                    self.objective.value(score).to_string().bright_green());
         }
 
-        Ok((min_cost, first_next))
+        Ok((score, first_next))
     }
 
-    fn reconstruct_path(&self, start: usize) -> Result<Vec<usize>> {
-        let mut path = vec![0, start];
-        let mut current = start;
-        let mut remaining: HashSet<usize> = (1..self.graph.size).collect();
-        remaining.remove(&start);
+    fn reconstruct_path(&self, first: usize) -> Result<Vec<usize>> {
+        let mut path: Vec<usize> = self.mode.start().into_iter().collect();
+        path.push(first);
+        let mut current = first;
+        let mut remaining: HashSet<usize> = self.inner_nodes().into_iter().collect();
+        remaining.remove(&first);
 
         if self.verbose {
             println!("\n{}", "=== REKONSTRUKSI JALUR ===".green().bold());
-            println!("Mulai dari: {} -> {}", 
-                    self.graph.node_names[0].bright_cyan(),
-                    self.graph.node_names[start].bright_cyan());
+            match self.mode.start() {
+                Some(start) => println!("Mulai dari: {} -> {}", 
+                        self.graph.node_names[start].bright_cyan(),
+                        self.graph.node_names[first].bright_cyan()),
+                None => println!("Mulai dari: {}", self.graph.node_names[first].bright_cyan()),
+            }
         }
 
         while !remaining.is_empty() {
             let mask = self.set_to_mask(&remaining);
-            if let Some(&(_, Some(next))) = self.memo.get(&(current, mask)) {
+            if let Some(&next) = self.memo.get(&(current, mask)).and_then(|(_, next)| next.first()) {
                 if self.verbose {
                     println!("Dari {} ke {}", 
                             self.graph.node_names[current].bright_cyan(),
@@ -280,21 +747,170 @@ impl TSPSolver {
             }
         }
 
-        path.push(0);
-        if self.verbose {
-            println!("Kembali ke: {}", self.graph.node_names[0].bright_cyan());
+        if let Some(end) = self.mode.end().filter(|&end| path.last() != Some(&end)) {
+            path.push(end);
+            if self.verbose {
+                println!("Kembali ke: {}", self.graph.node_names[end].bright_cyan());
+            }
         }
 
         Ok(path)
     }
 
-    fn validate_solution(&self, path: &[usize], expected_cost: i32) -> Result<bool> {
-        if !self.graph.is_valid_tour(path) {
-            return Ok(false);
+    /// Sensitivity of every edge with respect to the optimal tour found by `solve`
+    pub fn sensitivity_report(&self) -> Result<SensitivityReport> {
+        if self.final_choices.is_empty() {
+            return Err(TSPError::SolverError("Solver belum dijalankan".to_string()));
+        }
+        if self.objective != Objective::MinCost {
+            return Err(TSPError::SolverError(
+                "Analisis sensitivitas hanya tersedia untuk objektif biaya minimum".to_string()
+            ));
         }
 
-        let actual_cost = self.graph.calculate_path_cost(path)?;
-        Ok(actual_cost == expected_cost)
+        let optimal_path = self.reconstruct_path(self.final_choices[0])?;
+        let optimal_cost = self.graph.calculate_path_cost(&optimal_path)?;
+        SensitivityReport::analyze(&self.graph, self.mode, &self.constraints, optimal_cost, &optimal_path)
+    }
+
+    /// Number of optimal tours (every tie kept by the DP is a distinct tour)
+    pub fn count_optimal_tours(&self) -> Result<u64> {
+        if self.final_choices.is_empty() {
+            return Err(TSPError::SolverError("Solver belum dijalankan".to_string()));
+        }
+        if let Some(solver) = self.tie_solver()? {
+            return solver.count_optimal_tours();
+        }
+
+        let full_mask = self.full_mask();
+        let mut counts = HashMap::new();
+        Ok(self.final_choices
+            .iter()
+            .map(|&first| self.count_completions(first, full_mask & !(1u64 << first), &mut counts))
+            .sum())
+    }
+
+    fn count_completions(&self, current: usize, remaining: u64, counts: &mut HashMap<(usize, u64), u64>) -> u64 {
+        if remaining == 0 {
+            return 1;
+        }
+        if let Some(&count) = counts.get(&(current, remaining)) {
+            return count;
+        }
+
+        let count = match self.memo.get(&(current, remaining)) {
+            Some((_, next_nodes)) => next_nodes
+                .iter()
+                .map(|&next| self.count_completions(next, remaining & !(1u64 << next), counts))
+                .sum(),
+            None => 0,
+        };
+        counts.insert((current, remaining), count);
+        count
+    }
+
+    /// Optimal tours (at most `limit`), in canonical (lexicographic) order
+    pub fn optimal_tours(&self, limit: usize) -> Result<Vec<Vec<usize>>> {
+        if self.final_choices.is_empty() {
+            return Err(TSPError::SolverError("Solver belum dijalankan".to_string()));
+        }
+        if let Some(solver) = self.tie_solver()? {
+            return solver.optimal_tours(limit);
+        }
+
+        let full_mask = self.full_mask();
+        let mut tours = Vec::new();
+        for &first in &self.final_choices {
+            let mut path: Vec<usize> = self.mode.start().into_iter().collect();
+            path.push(first);
+            self.collect_tours(first, full_mask & !(1u64 << first), &mut path, &mut tours, limit);
+        }
+        Ok(tours)
+    }
+
+    fn collect_tours(
+        &self,
+        current: usize,
+        remaining: u64,
+        path: &mut Vec<usize>,
+        tours: &mut Vec<Vec<usize>>,
+        limit: usize,
+    ) {
+        if tours.len() >= limit {
+            return;
+        }
+
+        if remaining == 0 {
+            let mut tour = path.clone();
+            tour.extend(self.mode.end().filter(|&end| path.last() != Some(&end)));
+            tours.push(tour);
+            return;
+        }
+
+        if let Some((_, next_nodes)) = self.memo.get(&(current, remaining)) {
+            for &next in next_nodes {
+                path.push(next);
+                self.collect_tours(next, remaining & !(1u64 << next), path, tours, limit);
+                path.pop();
+            }
+        }
+    }
+
+    /// A min-max objective has no optimal suffixes, so its DP ties miss tours whose longest edge
+    /// lies in the prefix. Its optimal tours are those (of minimum length, for the lexicographic
+    /// objective) using only edges no longer than the optimal bottleneck: a min-cost DP on that
+    /// subgraph keeps exactly them as ties. None for the other objectives.
+    fn tie_solver(&self) -> Result<Option<TSPSolver>> {
+        if !self.objective.uses_bottleneck() {
+            return Ok(None);
+        }
+
+        let optimal_path = self.reconstruct_path(self.final_choices[0])?;
+        let bottleneck = RouteScore::of_path(&self.graph, &optimal_path)
+            .ok_or_else(|| TSPError::SolverError("Rute optimal memakai edge yang tidak ada".to_string()))?
+            .bottleneck;
+
+        let mut graph = self.graph.clone();
+        for (from, to) in (0..graph.size).flat_map(|i| (0..graph.size).map(move |j| (i, j))) {
+            if from == to || !graph.has_edge(from, to) {
+                continue;
+            }
+            if graph.get_distance(from, to) > bottleneck {
+                graph.missing_edges.insert((from, to));
+            } else if self.objective == Objective::Bottleneck {
+                graph.adjacency_matrix[from][to] = 0;
+            }
+        }
+
+        let mut solver = TSPSolver {
+            graph,
+            memo: HashMap::new(),
+            final_choices: Vec::new(),
+            constraints: self.constraints.clone(),
+            load_profile: self.load_profile.clone(),
+            mode: self.mode,
+            objective: Objective::MinCost,
+            verbose: false,
+            stats: SolverStats::default(),
+        };
+        solver.run_dp()?;
+        Ok(Some(solver))
+    }
+
+    /// Mask of every node between the fixed endpoints
+    fn full_mask(&self) -> u64 {
+        self.inner_nodes().iter().fold(0u64, |mask, &node| mask | (1u64 << node))
+    }
+
+    /// Route checks plus, for bottleneck objectives, the claimed longest edge
+    fn validate_solution(&self, path: &[usize], expected: RouteScore) -> TourVerification {
+        let mut verification = self.graph.verify_route(path, &self.mode, Some(expected.cost));
+        if self.objective.uses_bottleneck() {
+            if let Some(actual) = verification.actual_bottleneck.filter(|&actual| actual != expected.bottleneck) {
+                verification.issues.push(TourIssue::BottleneckMismatch { claimed: expected.bottleneck, actual });
+            }
+        }
+        verification
     }
 
     fn set_to_mask(&self, set: &HashSet<usize>) -> u64 {
@@ -353,6 +969,10 @@ impl std::fmt::Display for SolverStats {
         writeln!(f, "Cache Hits: {}", self.cache_hits)?;
         writeln!(f, "Cache Misses: {}", self.cache_misses)?;
         writeln!(f, "Max Memory States: {}", self.max_memory_states)?;
+        if self.states_recomputed + self.states_reused > 0 {
+            writeln!(f, "States Recomputed: {}", self.states_recomputed)?;
+            writeln!(f, "States Reused: {}", self.states_reused)?;
+        }
         
         let cache_total = self.cache_hits + self.cache_misses;
         if cache_total > 0 {
@@ -364,16 +984,72 @@ impl std::fmt::Display for SolverStats {
     }
 }
 
+impl TSPSolution {
+    /// Value of the route under the objective it was optimized for
+    pub fn objective_value(&self) -> i32 {
+        self.objective.value(RouteScore { cost: self.optimal_cost, bottleneck: self.bottleneck })
+    }
+
+    /// Comparison key under the same objective, smaller is better
+    pub fn objective_key(&self) -> (i32, i32) {
+        self.objective.key(RouteScore { cost: self.optimal_cost, bottleneck: self.bottleneck })
+    }
+
+    /// Same route as `path`, treating rotations of a closed tour as the same cycle
+    pub fn is_same_route(&self, path: &[usize]) -> bool {
+        if !self.mode.is_closed() {
+            return self.optimal_path == path;
+        }
+
+        let (ours, theirs) = (tour_cycle(&self.optimal_path), tour_cycle(path));
+        ours.len() == theirs.len()
+            && (ours.is_empty() || (0..theirs.len()).any(|shift| {
+                theirs[shift..].iter().chain(&theirs[..shift]).eq(ours.iter())
+            }))
+    }
+
+    /// The tour rotated to start (and end) at `origin`, None for open routes or unknown cities
+    pub fn rotated_to(&self, origin: usize) -> Option<Vec<usize>> {
+        if !self.mode.is_closed() {
+            return None;
+        }
+
+        let cycle = tour_cycle(&self.optimal_path);
+        let shift = cycle.iter().position(|&node| node == origin)?;
+        let mut path: Vec<usize> = cycle[shift..].iter().chain(&cycle[..shift]).copied().collect();
+        path.push(origin);
+        Some(path)
+    }
+}
+
+/// Cities of a closed tour without the repeated origin
+fn tour_cycle(path: &[usize]) -> &[usize] {
+    match path {
+        [first, .., last] if first == last => &path[..path.len() - 1],
+        _ => path,
+    }
+}
+
 impl std::fmt::Display for TSPSolution {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "=== TSP SOLUTION ===")?;
+        writeln!(f, "Origin: {}", self.origin + 1)?;
+        writeln!(f, "Objective: {}", self.objective)?;
+        writeln!(f, "Objective Value: {}", self.objective_value())?;
         writeln!(f, "Optimal Cost: {}", self.optimal_cost)?;
+        writeln!(f, "Bottleneck: {}", self.bottleneck)?;
         writeln!(f, "Optimal Path: {}", 
                 self.optimal_path.iter()
                     .map(|&i| (i + 1).to_string())
                     .collect::<Vec<_>>()
                     .join(" -> "))?;
+        if self.optimal_tour_count > 0 {
+            writeln!(f, "Optimal Tours: {}", self.optimal_tour_count)?;
+        }
         writeln!(f, "Valid: {}", if self.is_valid { "Yes" } else { "No" })?;
+        for issue in &self.verification.issues {
+            writeln!(f, "  [ERROR] {}", issue)?;
+        }
         writeln!(f, "{}", self.stats)?;
         Ok(())
     }
@@ -418,7 +1094,324 @@ mod tests {
 
         assert!(solution.is_valid);
         assert_eq!(solution.optimal_cost, 35);
+        assert_eq!(solution.verification.actual_cost, Some(35));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_path() -> Result<()> {
+        let matrix = vec![
+            vec![0, 10, 15, 20],
+            vec![5,  0,  9, 10],
+            vec![6, 13,  0, 12],
+            vec![8,  8,  9,  0],
+        ];
+        let graph = Graph::new(matrix)?;
+
+        let mut solver = TSPSolver::new(graph.clone())?
+            .with_mode(RouteMode::OpenPath { start: Some(0) });
+        let solution = solver.solve()?;
+        assert!(solution.is_valid);
+        assert_eq!(solution.optimal_cost, 29);
+        assert_eq!(solution.optimal_path, vec![0, 1, 3, 2]);
+
+        let mut solver = TSPSolver::new(graph)?
+            .with_mode(RouteMode::OpenPath { start: None });
+        let solution = solver.solve()?;
+        assert!(solution.is_valid);
+        assert_eq!(solution.optimal_cost, 23);
+        assert_eq!(solution.optimal_path, vec![3, 1, 2, 0]);
+        assert_eq!(solver.optimal_tours(5)?, vec![vec![3, 1, 2, 0]]);
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_path_between_fixed_cities() -> Result<()> {
+        let matrix = vec![
+            vec![0, 10, 15, 20],
+            vec![5,  0,  9, 10],
+            vec![6, 13,  0, 12],
+            vec![8,  8,  9,  0],
+        ];
+        let graph = Graph::new(matrix)?;
+
+        // 1 -> {2, 3} -> 4: 1-2-3-4 = 31, 1-3-2-4 = 38
+        let mut solver = TSPSolver::new(graph.clone())?
+            .with_mode(RouteMode::PathBetween { start: 0, end: 3 });
+        let solution = solver.solve()?;
+        assert!(solution.is_valid);
+        assert_eq!(solution.optimal_cost, 31);
+        assert_eq!(solution.optimal_path, vec![0, 1, 2, 3]);
+
+        let mut solver = TSPSolver::new(graph.clone())?
+            .with_mode(RouteMode::PathBetween { start: 2, end: 2 });
+        assert!(solver.solve().is_err());
+
+        let two_cities = Graph::new(vec![vec![0, 4], vec![7, 0]])?;
+        let mut solver = TSPSolver::new(two_cities)?
+            .with_mode(RouteMode::PathBetween { start: 1, end: 0 });
+        let solution = solver.solve()?;
+        assert_eq!(solution.optimal_path, vec![1, 0]);
+        assert_eq!(solution.optimal_cost, 7);
+        assert_eq!(solver.optimal_tours(5)?, vec![vec![1, 0]]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_selectable_origin() -> Result<()> {
+        let matrix = vec![
+            vec![0, 10, 15, 20],
+            vec![5,  0,  9, 10],
+            vec![6, 13,  0, 12],
+            vec![8,  8,  9,  0],
+        ];
+        let graph = Graph::new(matrix)?;
+
+        let from_first = TSPSolver::new(graph.clone())?.solve()?;
+        let mut solver = TSPSolver::new(graph)?.with_mode(RouteMode::Tour { origin: 2 });
+        let from_third = solver.solve()?;
+
+        assert!(from_third.is_valid);
+        assert_eq!(from_third.origin, 2);
+        assert_eq!(from_third.optimal_cost, from_first.optimal_cost);
+        assert_eq!(from_third.optimal_path, vec![2, 0, 1, 3, 2]);
+
+        // Same cycle, only rotated
+        assert_ne!(from_third.optimal_path, from_first.optimal_path);
+        assert!(from_third.is_same_route(&from_first.optimal_path));
+        assert_eq!(from_first.rotated_to(2), Some(from_third.optimal_path.clone()));
+        assert!(!from_third.is_same_route(&[2, 1, 0, 3, 2]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_incremental_matches_fresh_solve() -> Result<()> {
+        let matrix = vec![
+            vec![0, 10, 15, 20, 11],
+            vec![5,  0,  9, 10, 7],
+            vec![6, 13,  0, 12, 14],
+            vec![8,  8,  9,  0, 3],
+            vec![9,  4, 16,  2, 0],
+        ];
+
+        let graph = Graph::new(matrix)?;
+        let mut solver = TSPSolver::new(graph.clone())?;
+        solver.solve()?;
+
+        let changes = [(3, 2, 30), (2, 0, 1)];
+        let updated = solver.update_edge_weights(&changes)?;
+
+        let mut modified = graph;
+        for &(from, to, weight) in &changes {
+            modified.adjacency_matrix[from][to] = weight;
+        }
+        let fresh = TSPSolver::new(modified)?.solve()?;
+
+        assert!(updated.is_valid);
+        assert_eq!(updated.optimal_cost, fresh.optimal_cost);
+        assert_eq!(updated.optimal_path, fresh.optimal_path);
+        assert_eq!(updated.optimal_tour_count, fresh.optimal_tour_count);
+        assert!(updated.stats.states_recomputed > 0);
+        assert!(updated.stats.states_recomputed < fresh.stats.states_computed);
+        assert_eq!(
+            updated.stats.states_recomputed + updated.stats.states_reused,
+            fresh.stats.states_computed
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_enumerate_co_optimal_tours() -> Result<()> {
+        // Symmetric square: both directions around the ring cost 4
+        let matrix = vec![
+            vec![0, 1, 5, 1],
+            vec![1, 0, 1, 5],
+            vec![5, 1, 0, 1],
+            vec![1, 5, 1, 0],
+        ];
+
+        let graph = Graph::new(matrix)?;
+        let mut solver = TSPSolver::new(graph)?;
+        let solution = solver.solve()?;
+
+        assert_eq!(solution.optimal_cost, 4);
+        assert_eq!(solution.optimal_tour_count, 2);
+        assert_eq!(solution.optimal_path, vec![0, 1, 2, 3, 0]);
+        assert_eq!(solver.optimal_tours(10)?, vec![vec![0, 1, 2, 3, 0], vec![0, 3, 2, 1, 0]]);
+        assert_eq!(solver.optimal_tours(1)?.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_edge_constraints() -> Result<()> {
+        let matrix = vec![
+            vec![0, 10, 15, 20],
+            vec![5,  0,  9, 10],
+            vec![6, 13,  0, 12],
+            vec![8,  8,  9,  0],
+        ];
+
+        // Without 0 -> 1 the best tour is 0 -> 2 -> 3 -> 1 -> 0 (40)
+        let mut graph = Graph::new(matrix)?;
+        graph.forbid_edge(0, 1)?;
+        let solution = TSPSolver::new(graph.clone())?.solve()?;
+        assert_eq!(solution.optimal_cost, 40);
+        assert_eq!(solution.optimal_path, vec![0, 2, 3, 1, 0]);
+
+        // Also requiring 2 -> 1 leaves 0 -> 2 -> 1 -> 3 -> 0 (46) and 0 -> 3 -> 2 -> 1 -> 0 (47)
+        graph.require_edge(2, 1)?;
+        let solution = TSPSolver::new(graph.clone())?.solve()?;
+        assert!(solution.is_valid);
+        assert_eq!(solution.optimal_cost, 46);
+        assert_eq!(solution.optimal_path, vec![0, 2, 1, 3, 0]);
+        assert_eq!(solution.optimal_tour_count, 1);
+
+        // Requiring 1 -> 2 as well closes the cycle 1 -> 2 -> 1 before the tour is complete
+        graph.require_edge(1, 2)?;
+        let message = TSPSolver::new(graph.clone()).err().unwrap().to_string();
+        assert!(message.contains("subtour"), "{}", message);
+
+        // No structural conflict, yet 0 -> 1 -> 2 -> 0 and 0 -> 2 -> 1 -> 0 both break a constraint
+        let mut graph = Graph::new(vec![
+            vec![0, 1, 1],
+            vec![1, 0, 1],
+            vec![1, 1, 0],
+        ])?;
+        graph.require_edge(0, 1)?;
+        graph.forbid_edge(1, 2)?;
+        let message = TSPSolver::new(graph)?.solve().unwrap_err().to_string();
+        assert!(message.contains("batasan edge"), "{}", message);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_objectives() -> Result<()> {
+        // Two tours of length 22: 0-3-1-2-0 (longest edge 9) and 0-3-2-1-0 (longest edge 7)
+        let matrix = vec![
+            vec![0, 8, 9, 5],
+            vec![4, 0, 9, 6],
+            vec![6, 7, 0, 8],
+            vec![9, 2, 6, 0],
+        ];
+        let graph = Graph::new(matrix)?;
+        let solve = |objective| TSPSolver::new(graph.clone())?.with_objective(objective).solve();
+
+        let min_cost = solve(Objective::MinCost)?;
+        assert_eq!((min_cost.optimal_cost, min_cost.bottleneck), (22, 9));
+        assert_eq!(min_cost.optimal_path, vec![0, 3, 1, 2, 0]);
+        assert_eq!(min_cost.optimal_tour_count, 2);
+
+        let lexicographic = solve(Objective::CostThenBottleneck)?;
+        assert_eq!((lexicographic.optimal_cost, lexicographic.bottleneck), (22, 7));
+        assert_eq!(lexicographic.optimal_path, vec![0, 3, 2, 1, 0]);
+        assert_eq!(lexicographic.optimal_tour_count, 1);
+
+        let bottleneck = solve(Objective::Bottleneck)?;
+        assert!(bottleneck.is_valid);
+        assert_eq!(bottleneck.objective_value(), 7);
+        assert_eq!(bottleneck.verification.actual_bottleneck, Some(7));
+
+        let longest = solve(Objective::MaxCost)?;
+        assert!(longest.is_valid);
+        assert_eq!(longest.objective_value(), 34);
+        assert_eq!(longest.optimal_path, vec![0, 1, 2, 3, 0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bottleneck_tie_count() -> Result<()> {
+        // Every tour leaves city 0 on a 10, so all 6 tours share the bottleneck, even the ones
+        // whose suffix is worse than the best suffix of its DP state
+        let graph = Graph::new(vec![
+            vec![0, 10, 10, 10],
+            vec![1,  0,  2,  3],
+            vec![4,  5,  0,  6],
+            vec![7,  8,  9,  0],
+        ])?;
+        let mut solver = TSPSolver::new(graph)?.with_objective(Objective::Bottleneck);
+        let solution = solver.solve()?;
+
+        assert_eq!(solution.objective_value(), 10);
+        assert_eq!(solution.optimal_tour_count, 6);
+        assert_eq!(solver.optimal_tours(10)?.len(), 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_precedence_constraints() -> Result<()> {
+        let matrix = vec![
+            vec![0, 10, 15, 20],
+            vec![5,  0,  9, 10],
+            vec![6, 13,  0, 12],
+            vec![8,  8,  9,  0],
+        ];
+
+        // Node 2 before node 1 rules out the 35 tour: best is 0 -> 2 -> 3 -> 1 -> 0
+        let mut graph = Graph::new(matrix)?;
+        graph.add_precedence(2, 1)?;
+        let solution = TSPSolver::new(graph.clone())?.solve()?;
+        assert_eq!(solution.optimal_cost, 40);
+        assert_eq!(solution.optimal_path, vec![0, 2, 3, 1, 0]);
+        assert!(solution.is_valid);
+
+        // The fixed end of a path cannot precede anything
+        graph.add_precedence(3, 2)?;
+        let mut solver = TSPSolver::new(graph)?.with_mode(RouteMode::PathBetween { start: 0, end: 3 });
+        assert!(solver.solve().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sparse_graph() -> Result<()> {
+        let matrix = vec![
+            vec![0, 10, 15, 20],
+            vec![5,  0,  9, 10],
+            vec![6, 13,  0, 12],
+            vec![8,  8,  9,  0],
+        ];
+
+        // Missing 0 -> 1 behaves like a forbidden edge
+        let mut graph = Graph::new(matrix)?;
+        graph.remove_edge(0, 1)?;
+        let solution = TSPSolver::new(graph.clone())?.solve()?;
+        assert_eq!(solution.optimal_cost, 40);
+        assert_eq!(solution.optimal_path, vec![0, 2, 3, 1, 0]);
+        assert!(solution.is_valid);
+
+        // Nothing enters the third city any more
+        for from in [0, 1, 3] {
+            graph.remove_edge(from, 2)?;
+        }
+        let message = TSPSolver::new(graph)?.solve().unwrap_err().to_string();
+        assert!(message.contains("Kota_3 tidak punya edge masuk"), "{}", message);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_zero_and_negative_weights() -> Result<()> {
+        // 0 -> 1 is free and 1 -> 2 pays back, both are real edges
+        let matrix = vec![
+            vec![0, 0, 5],
+            vec![4, 0, -3],
+            vec![2, 6, 0],
+        ];
+        let solution = TSPSolver::new(Graph::new(matrix)?)?.solve()?;
+        assert_eq!(solution.optimal_cost, -1);
+        assert_eq!(solution.optimal_path, vec![0, 1, 2, 0]);
+        assert_eq!(solution.bottleneck, 2);
+        assert!(solution.is_valid);
+
+        Ok(())
+    }
+}
@@ -1,5 +1,18 @@
 // Libraries
-use crate::{Graph, tsp_solver::TSPSolution};
+use crate::{Graph, k_best::RankedTour, sensitivity::SensitivityReport, tsp_solver::TSPSolution};
+use crate::tsp_solver::Objective;
+use crate::cvrp::CVRPSolution;
+use crate::gtsp::GTSPSolution;
+use crate::hamiltonian::HamiltonianReport;
+use crate::metric_closure::ClosureSolution;
+use crate::multi_tsp::{MultiObjective, MultiTSPSolution};
+use crate::pareto::ParetoFront;
+use crate::pickup_delivery::{DeliveryRequest, PickupDeliverySolution};
+use crate::prize_collecting::{CityReward, SelectiveMode, SelectiveTourSolution};
+use crate::robust::{CostInterval, EdgeInterval, RobustObjective, RobustSolution};
+use crate::stochastic::{EdgeDistribution, StochasticObjective, StochasticSolution, REPORTED_PERCENTILES};
+use crate::time_dependent::TimeDependentSolution;
+use crate::time_windows::{CityTiming, TimeWindowSolution};
 use colored::*;
 use prettytable::{Cell, Row, Table};
 use std::collections::HashSet;
@@ -7,17 +20,31 @@ use std::collections::HashSet;
 pub struct Visualizer;
 
 impl Visualizer {
+    fn objective_label(objective: Objective) -> &'static str {
+        match objective {
+            Objective::MinCost => "biaya total minimum",
+            Objective::Bottleneck => "edge terpanjang minimum (bottleneck)",
+            Objective::MaxCost => "biaya total maksimum",
+            Objective::CostThenBottleneck => "biaya total minimum, lalu bottleneck minimum",
+        }
+    }
+
     pub fn display_solution(graph: &Graph, solution: &TSPSolution) {
         println!("{}", "=== SOLUSI TSP ===".bright_blue().bold());
         
         // Header info
         println!("Graf: {} kota", graph.size.to_string().bright_green());
+        println!("Kota Asal: {}", graph.node_names[solution.origin].bright_cyan());
+        println!("Objektif: {}", Self::objective_label(solution.objective));
         println!("Status: {}", 
                 if solution.is_valid { 
                     "[VALID]".bright_green() 
                 } else { 
                     "[INVALID]".bright_red() 
                 });
+        for issue in &solution.verification.issues {
+            println!("  {} {}", "[ERROR]".bright_red(), issue);
+        }
         println!();
 
         // Optimal path
@@ -31,9 +58,20 @@ impl Visualizer {
         println!();
 
         // Optimal cost
+        println!("{} {}", 
+                "Nilai Objektif:".bright_yellow().bold(), 
+                solution.objective_value().to_string().bright_green().bold());
         println!("{} {}", 
                 "Biaya Total:".bright_yellow().bold(), 
-                solution.optimal_cost.to_string().bright_green().bold());
+                solution.optimal_cost.to_string().bright_green());
+        println!("{} {}", 
+                "Bottleneck (edge terpanjang):".bright_yellow().bold(), 
+                solution.bottleneck.to_string().bright_green());
+        if solution.optimal_tour_count > 0 {
+            println!("{} {}", 
+                    "Jumlah Tour Optimal:".bright_yellow().bold(), 
+                    solution.optimal_tour_count.to_string().bright_green());
+        }
         println!();
 
         Self::display_journey_details(graph, &solution.optimal_path, solution.optimal_cost);
@@ -96,8 +134,7 @@ impl Visualizer {
 
         for i in 0..graph.size {
             for j in 0..graph.size {
-                if i != j && graph.adjacency_matrix[i][j] > 0 {
-                    let weight = graph.adjacency_matrix[i][j];
+                if let Some(weight) = graph.edge_weight(i, j) {
                     let line = format!("  {} {} {} ({})", 
                                      graph.node_names[i],
                                      if tour_edges.contains(&(i, j)) { "===" } else { "---" },
@@ -119,10 +156,13 @@ impl Visualizer {
     pub fn display_tour_sequence(graph: &Graph, path: &[usize]) {
         println!("{}", "=== URUTAN KUNJUNGAN ===".bright_blue().bold());
         
+        // Open paths never repeat their first node
+        let is_closed = path.len() > 1 && path.first() == path.last();
         for (step, &node) in path.iter().enumerate() {
             let action = match step {
                 0 => "Mulai di",
-                s if s == path.len() - 1 => "Kembali ke",
+                s if s == path.len() - 1 && is_closed => "Kembali ke",
+                s if s == path.len() - 1 => "Berakhir di",
                 _ => "Kunjungi",
             };
 
@@ -134,6 +174,764 @@ impl Visualizer {
         println!();
     }
 
+    /// Co-optimal tours (tours tied with the optimum)
+    pub fn display_optimal_tours(graph: &Graph, tours: &[Vec<usize>], total_count: u64) {
+        println!("{}", "=== SEMUA TOUR OPTIMAL ===".bright_blue().bold());
+        println!("Jumlah tour optimal: {}", total_count.to_string().bright_green());
+
+        for (i, tour) in tours.iter().enumerate() {
+            let path_str = tour
+                .iter()
+                .map(|&node| graph.node_names[node].bright_cyan().to_string())
+                .collect::<Vec<_>>()
+                .join(" → ");
+            println!("  {}. {}", i + 1, path_str);
+        }
+
+        if (tours.len() as u64) < total_count {
+            println!("  ... ({} tour lainnya tidak ditampilkan)", total_count - tours.len() as u64);
+        }
+        println!();
+    }
+
+    /// k-best ranking followed by the journey table of each tour
+    pub fn display_k_best(graph: &Graph, tours: &[RankedTour], objective: Objective) {
+        println!("{}", "=== PERINGKAT K TOUR TERBAIK ===".bright_blue().bold());
+        println!("Objektif: {}", Self::objective_label(objective));
+
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("Rank").style_spec("Fb"),
+            Cell::new("Biaya").style_spec("Fb"),
+            Cell::new("Bottleneck").style_spec("Fb"),
+            Cell::new("Selisih").style_spec("Fb"),
+            Cell::new("Jalur").style_spec("Fb"),
+        ]));
+
+        for tour in tours {
+            let path_str = tour.path
+                .iter()
+                .map(|&i| graph.node_names[i].as_str())
+                .collect::<Vec<_>>()
+                .join(" → ");
+
+            table.add_row(Row::new(vec![
+                Cell::new(&tour.rank.to_string()),
+                Cell::new(&tour.cost.to_string()).style_spec("Fg"),
+                Cell::new(&tour.bottleneck.to_string()),
+                Cell::new(&format!("+{}", tour.gap_from_optimal)).style_spec("Fr"),
+                Cell::new(&path_str),
+            ]));
+        }
+
+        table.printstd();
+        println!();
+
+        for tour in tours {
+            println!("{} (biaya {}, +{} dari optimal)", 
+                    format!("Tour #{}", tour.rank).bright_cyan().bold(),
+                    tour.cost,
+                    tour.gap_from_optimal);
+            Self::display_journey_details(graph, &tour.path, tour.cost);
+        }
+    }
+
+    /// Sensitivity table (fragile edges highlighted)
+    pub fn display_sensitivity(graph: &Graph, report: &SensitivityReport) {
+        println!("{}", "=== ANALISIS SENSITIVITAS ===".bright_blue().bold());
+        println!("Biaya optimal: {}", report.optimal_cost.to_string().bright_green());
+        println!("  Dalam tour : kenaikan bobot maksimum sebelum tour lain menjadi lebih baik");
+        println!("  Luar tour  : penurunan bobot yang diperlukan agar edge masuk tour optimal");
+        println!();
+
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("Dari").style_spec("Fb"),
+            Cell::new("Ke").style_spec("Fb"),
+            Cell::new("Bobot").style_spec("Fb"),
+            Cell::new("Dalam Tour").style_spec("Fb"),
+            Cell::new("Toleransi").style_spec("Fb"),
+            Cell::new("Status").style_spec("Fb"),
+        ]));
+
+        for edge in &report.edges {
+            let tolerance = match edge.tolerance {
+                Some(t) if edge.in_tour => format!("+{}", t),
+                Some(t) => format!("-{}", t),
+                None => "∞".to_string(),
+            };
+            let (status, style) = if edge.is_fragile() {
+                ("[FRAGILE]", "FrB")
+            } else {
+                ("[STABLE]", "Fg")
+            };
+
+            table.add_row(Row::new(vec![
+                Cell::new(&graph.node_names[edge.from]),
+                Cell::new(&graph.node_names[edge.to]),
+                Cell::new(&edge.weight.to_string()),
+                Cell::new(if edge.in_tour { "Ya" } else { "Tidak" }),
+                Cell::new(&tolerance).style_spec(style),
+                Cell::new(status).style_spec(style),
+            ]));
+        }
+
+        table.printstd();
+        println!("Edge rapuh: {}", report.fragile_edges().count().to_string().bright_red());
+        println!();
+    }
+
+    /// Time-window route with the arrival, waiting and departure time of every stop
+    pub fn display_time_window_solution(graph: &Graph, solution: &TimeWindowSolution, timings: &[CityTiming]) {
+        println!("{}", "=== SOLUSI TSP DENGAN JENDELA WAKTU ===".bright_blue().bold());
+        println!("Metode: {}", if solution.is_exact { "DP Eksak" } else { "Heuristik" });
+        println!("Status: {}", 
+                if solution.is_valid { 
+                    "[VALID]".bright_green() 
+                } else { 
+                    "[INVALID]".bright_red() 
+                });
+        println!("{} {}", 
+                "Biaya Total:".bright_yellow().bold(), 
+                solution.total_cost.to_string().bright_green().bold());
+        println!("{} {}", 
+                "Waktu Selesai:".bright_yellow().bold(), 
+                solution.completion_time.to_string().bright_green());
+        println!();
+
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("Step").style_spec("Fb"),
+            Cell::new("Kota").style_spec("Fb"),
+            Cell::new("Jendela").style_spec("Fb"),
+            Cell::new("Tiba").style_spec("Fb"),
+            Cell::new("Tunggu").style_spec("Fb"),
+            Cell::new("Berangkat").style_spec("Fb"),
+        ]));
+
+        for (step, stop) in solution.schedule.iter().enumerate() {
+            let window = match timings[stop.node].window {
+                Some(w) => format!("[{}, {}]", w.earliest, w.latest),
+                None => "-".to_string(),
+            };
+
+            table.add_row(Row::new(vec![
+                Cell::new(&(step + 1).to_string()),
+                Cell::new(&graph.node_names[stop.node]),
+                Cell::new(&window),
+                Cell::new(&stop.arrival.to_string()),
+                Cell::new(&stop.wait.to_string()).style_spec(if stop.wait > 0 { "Fy" } else { "" }),
+                Cell::new(&stop.departure.to_string()),
+            ]));
+        }
+
+        table.printstd();
+        println!();
+        println!("{}", solution.stats);
+    }
+
+    /// Time-dependent route with the departure slot, waiting and arrival of every leg
+    pub fn display_time_dependent_solution(graph: &Graph, solution: &TimeDependentSolution) {
+        println!("{}", "=== SOLUSI TSP BERGANTUNG WAKTU ===".bright_blue().bold());
+        println!("Metode: {}", if solution.is_exact { "DP Eksak" } else { "Heuristik" });
+        println!("Status: {}", 
+                if solution.is_valid { 
+                    "[VALID]".bright_green() 
+                } else { 
+                    "[INVALID]".bright_red() 
+                });
+        for issue in &solution.verification.issues {
+            println!("  {} {}", "[ERROR]".bright_red(), issue);
+        }
+        println!("{} {}", 
+                "Waktu Mulai:".bright_yellow().bold(), 
+                solution.start_time.to_string().bright_green());
+        println!("{} {}", 
+                "Durasi Total:".bright_yellow().bold(), 
+                solution.duration.to_string().bright_green().bold());
+        println!();
+
+        let criteria = graph.criteria();
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("Step").style_spec("Fb"),
+            Cell::new("Dari").style_spec("Fb"),
+            Cell::new("Ke").style_spec("Fb"),
+            Cell::new("Tunggu").style_spec("Fb"),
+            Cell::new("Berangkat").style_spec("Fb"),
+            Cell::new("Tiba").style_spec("Fb"),
+            Cell::new("Matriks").style_spec("Fb"),
+        ]));
+
+        for (step, leg) in solution.legs.iter().enumerate() {
+            let wait = leg.departure - leg.ready;
+            table.add_row(Row::new(vec![
+                Cell::new(&(step + 1).to_string()),
+                Cell::new(&graph.node_names[leg.from]),
+                Cell::new(&graph.node_names[leg.to]),
+                Cell::new(&wait.to_string()).style_spec(if wait > 0 { "Fy" } else { "" }),
+                Cell::new(&leg.departure.to_string()),
+                Cell::new(&leg.arrival.to_string()).style_spec("Fg"),
+                Cell::new(criteria[leg.criterion]),
+            ]));
+        }
+
+        table.printstd();
+        println!();
+        println!("{}", solution.stats);
+    }
+
+    pub fn display_robust_solution(graph: &Graph, solution: &RobustSolution, intervals: &[EdgeInterval]) {
+        println!("{}", "=== SOLUSI TSP ROBUST ===".bright_blue().bold());
+        println!("Metode: {}", if solution.is_exact { "Eksak" } else { "Heuristik" });
+        println!("Objektif: {}", match solution.objective {
+            RobustObjective::MinMaxRegret => "regret terburuk minimum",
+            RobustObjective::WorstCaseCost => "biaya terburuk minimum",
+        });
+        println!("Status: {}", 
+                if solution.is_valid { 
+                    "[VALID]".bright_green() 
+                } else { 
+                    "[INVALID]".bright_red() 
+                });
+        for issue in &solution.verification.issues {
+            println!("  {} {}", "[ERROR]".bright_red(), issue);
+        }
+        println!("{} {}", 
+                "Rute:".bright_yellow().bold(),
+                solution.path.iter()
+                    .map(|&i| graph.node_names[i].as_str())
+                    .collect::<Vec<_>>()
+                    .join(" -> ")
+                    .bright_cyan());
+        println!("{} [{}, {}]", 
+                "Rentang Biaya:".bright_yellow().bold(), 
+                solution.best_case_cost.to_string().bright_green(),
+                solution.worst_case_cost.to_string().bright_red());
+
+        match &solution.scenario {
+            Some(scenario) => {
+                println!("{} {}", 
+                        "Regret Terburuk:".bright_yellow().bold(), 
+                        scenario.regret.to_string().bright_green().bold());
+                println!("Skenario: edge rute ini di batas atas, edge lain di batas bawah");
+                println!("Rute terbaik pada skenario itu ({}): {}", 
+                        scenario.rival_cost,
+                        scenario.rival.iter()
+                            .map(|&i| graph.node_names[i].as_str())
+                            .collect::<Vec<_>>()
+                            .join(" -> "));
+            }
+            None => println!("Regret Terburuk: {}", "tidak dihitung (graf terlalu besar)".bright_yellow()),
+        }
+        println!();
+
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("Step").style_spec("Fb"),
+            Cell::new("Dari").style_spec("Fb"),
+            Cell::new("Ke").style_spec("Fb"),
+            Cell::new("Bawah").style_spec("Fb"),
+            Cell::new("Atas").style_spec("Fb"),
+        ]));
+
+        for (step, w) in solution.path.windows(2).enumerate() {
+            let interval = intervals.iter()
+                .find(|edge| edge.from == w[0] && edge.to == w[1])
+                .map_or_else(|| {
+                    let weight = graph.get_distance(w[0], w[1]);
+                    CostInterval { low: weight, high: weight }
+                }, |edge| edge.interval);
+            let uncertain = if interval.low < interval.high { "Fy" } else { "" };
+            table.add_row(Row::new(vec![
+                Cell::new(&(step + 1).to_string()),
+                Cell::new(&graph.node_names[w[0]]),
+                Cell::new(&graph.node_names[w[1]]),
+                Cell::new(&interval.low.to_string()).style_spec(uncertain),
+                Cell::new(&interval.high.to_string()).style_spec(uncertain),
+            ]));
+        }
+
+        table.printstd();
+        println!();
+        println!("{}", solution.stats);
+    }
+
+    pub fn display_stochastic_solution(graph: &Graph, solution: &StochasticSolution, distributions: &[EdgeDistribution]) {
+        println!("{}", "=== SOLUSI TSP STOKASTIK ===".bright_blue().bold());
+        println!("Metode: {}", if solution.is_exact { "Eksak" } else { "Heuristik" });
+        println!("Objektif: {}", match solution.objective {
+            StochasticObjective::Expected => "biaya harapan minimum".to_string(),
+            StochasticObjective::Quantile(q) => format!("persentil ke-{} minimum", q * 100.0),
+        });
+        println!("Status: {}", 
+                if solution.is_valid { 
+                    "[VALID]".bright_green() 
+                } else { 
+                    "[INVALID]".bright_red() 
+                });
+        for issue in &solution.verification.issues {
+            println!("  {} {}", "[ERROR]".bright_red(), issue);
+        }
+        println!("{} {}", 
+                "Rute:".bright_yellow().bold(),
+                solution.path.iter()
+                    .map(|&i| graph.node_names[i].as_str())
+                    .collect::<Vec<_>>()
+                    .join(" -> ")
+                    .bright_cyan());
+        println!("{} {}", 
+                "Nilai Objektif:".bright_yellow().bold(), 
+                format!("{:.2}", solution.objective_value).bright_green().bold());
+        println!();
+
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("Step").style_spec("Fb"),
+            Cell::new("Dari").style_spec("Fb"),
+            Cell::new("Ke").style_spec("Fb"),
+            Cell::new("Biaya").style_spec("Fb"),
+            Cell::new("Rata-rata").style_spec("Fb"),
+        ]));
+
+        for (step, w) in solution.path.windows(2).enumerate() {
+            let distribution = distributions.iter()
+                .find(|edge| edge.from == w[0] && edge.to == w[1])
+                .map(|edge| &edge.distribution);
+            table.add_row(Row::new(vec![
+                Cell::new(&(step + 1).to_string()),
+                Cell::new(&graph.node_names[w[0]]),
+                Cell::new(&graph.node_names[w[1]]),
+                match distribution {
+                    Some(distribution) => Cell::new(&distribution.to_string()).style_spec("Fy"),
+                    None => Cell::new(&graph.get_distance(w[0], w[1]).to_string()),
+                },
+                Cell::new(&format!("{:.2}", distribution.map_or(graph.get_distance(w[0], w[1]) as f64, |d| d.mean()))),
+            ]));
+        }
+
+        table.printstd();
+        println!();
+
+        let summary = &solution.summary;
+        println!("{}", format!("Distribusi Biaya Tour ({} sampel Monte Carlo)", summary.samples.len()).bright_yellow().bold());
+        println!("  Rata-rata: {:.2} (eksak: {:.2})", summary.mean, solution.expected_cost);
+        println!("  Variansi: {:.2}, Simpangan Baku: {:.2}", summary.variance, summary.std_dev);
+        println!("  Minimum: {:.2}, Maksimum: {:.2}", summary.min, summary.max);
+
+        let mut percentiles = REPORTED_PERCENTILES.to_vec();
+        if let StochasticObjective::Quantile(q) = solution.objective {
+            if !percentiles.contains(&q) {
+                percentiles.push(q);
+                percentiles.sort_by(f64::total_cmp);
+            }
+        }
+        for q in percentiles {
+            println!("  P{}: {:.2}", q * 100.0, summary.quantile(q));
+        }
+
+        // Histogram, 10 equal-width bins between min and max
+        const BINS: usize = 10;
+        const BAR_WIDTH: usize = 40;
+        let width = (summary.max - summary.min) / BINS as f64;
+        let mut counts = [0usize; BINS];
+        for &cost in &summary.samples {
+            let bin = if width > 0.0 { ((cost - summary.min) / width) as usize } else { 0 };
+            counts[bin.min(BINS - 1)] += 1;
+        }
+        let largest = counts.iter().copied().max().unwrap_or(1).max(1);
+        println!();
+        for (bin, &count) in counts.iter().enumerate() {
+            let low = summary.min + width * bin as f64;
+            println!("  {:>9.2} - {:<9.2} {} {}", 
+                    low, low + width,
+                    "█".repeat(count * BAR_WIDTH / largest).bright_cyan(),
+                    count);
+            if width == 0.0 {
+                break;
+            }
+        }
+        println!();
+        println!("{}", solution.stats);
+    }
+
+    pub fn display_hamiltonian_report(graph: &Graph, report: &HamiltonianReport) {
+        println!("{}", "=== SIKLUS HAMILTON ===".bright_blue().bold());
+        println!("Status: {}", 
+                if report.exists() { 
+                    "[ADA]".bright_green() 
+                } else { 
+                    "[TIDAK ADA]".bright_red() 
+                });
+        println!("{} {}", 
+                "Siklus Berarah:".bright_yellow().bold(), 
+                report.directed_count.to_string().bright_green().bold());
+        match &report.undirected_count {
+            Some(count) => println!("{} {} (kedua arah dihitung sekali)", 
+                    "Siklus Tak Berarah:".bright_yellow().bold(), 
+                    count.to_string().bright_green()),
+            None => println!("Siklus Tak Berarah: {}", "- (edge tidak simetris)".dimmed()),
+        }
+        if let Some(witness) = &report.witness {
+            println!("{} {}", 
+                    "Contoh Siklus:".bright_yellow().bold(),
+                    witness.iter()
+                        .map(|&i| graph.node_names[i].as_str())
+                        .collect::<Vec<_>>()
+                        .join(" -> ")
+                        .bright_cyan());
+        }
+        println!();
+        println!("{}", report.stats);
+    }
+
+    /// Pickup-and-delivery route with the load on board after every stop
+    pub fn display_pickup_delivery_solution(graph: &Graph, solution: &PickupDeliverySolution, requests: &[DeliveryRequest]) {
+        println!("{}", "=== SOLUSI PICKUP AND DELIVERY ===".bright_blue().bold());
+        println!("Metode: {}", if solution.is_exact { "DP Eksak" } else { "Heuristik" });
+        println!("Status: {}", 
+                if solution.is_valid { 
+                    "[VALID]".bright_green() 
+                } else { 
+                    "[INVALID]".bright_red() 
+                });
+        for issue in &solution.verification.issues {
+            println!("  {} {}", "[ERROR]".bright_red(), issue);
+        }
+        println!("{} {}", 
+                "Biaya Total:".bright_yellow().bold(), 
+                solution.total_cost.to_string().bright_green().bold());
+        println!("Kapasitas Kendaraan: {}", solution.capacity.to_string().bright_green());
+        println!();
+
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("Step").style_spec("Fb"),
+            Cell::new("Dari").style_spec("Fb"),
+            Cell::new("Ke").style_spec("Fb"),
+            Cell::new("Jarak").style_spec("Fb"),
+            Cell::new("Aksi").style_spec("Fb"),
+            Cell::new("Muatan").style_spec("Fb"),
+        ]));
+
+        let mut served = HashSet::from([solution.path[0]]);
+        for (step, window) in solution.path.windows(2).enumerate() {
+            let (from, to) = (window[0], window[1]);
+
+            // Actions only happen at the first visit of a city
+            let mut actions = Vec::new();
+            if served.insert(to) {
+                for request in requests {
+                    if request.delivery == to {
+                        actions.push(format!("-{} (dari {})", request.load, graph.node_names[request.pickup]));
+                    }
+                    if request.pickup == to {
+                        actions.push(format!("+{} (ke {})", request.load, graph.node_names[request.delivery]));
+                    }
+                }
+            }
+
+            let load = solution.loads[step + 1];
+            table.add_row(Row::new(vec![
+                Cell::new(&(step + 1).to_string()),
+                Cell::new(&graph.node_names[from]),
+                Cell::new(&graph.node_names[to]),
+                Cell::new(&graph.get_distance(from, to).to_string()),
+                Cell::new(&if actions.is_empty() { "-".to_string() } else { actions.join(", ") }),
+                Cell::new(&format!("{}/{}", load, solution.capacity))
+                    .style_spec(if load > solution.capacity { "Fr" } else { "Fg" }),
+            ]));
+        }
+
+        table.printstd();
+        println!();
+        println!("{}", solution.stats);
+    }
+
+    /// Summary of every salesman followed by the journey table of each tour
+    pub fn display_multi_tsp_solution(graph: &Graph, solution: &MultiTSPSolution) {
+        println!("{}", "=== SOLUSI MULTIPLE TSP ===".bright_blue().bold());
+        println!("Metode: {}", if solution.is_exact { "DP Eksak" } else { "Heuristik" });
+        println!("Objektif: {}", match solution.objective {
+            MultiObjective::MinSum => "minimasi total jarak",
+            MultiObjective::MinMax => "minimasi tour terpanjang",
+        });
+        println!("Depot: {}", graph.node_names[solution.depot].bright_cyan());
+        println!("Status: {}", 
+                if solution.is_valid { 
+                    "[VALID]".bright_green() 
+                } else { 
+                    "[INVALID]".bright_red() 
+                });
+        println!("{} {}", 
+                "Biaya Total:".bright_yellow().bold(), 
+                solution.total_cost.to_string().bright_green().bold());
+        println!("{} {}", 
+                "Tour Terpanjang:".bright_yellow().bold(), 
+                solution.longest_tour.to_string().bright_green());
+        println!();
+
+        for (i, tour) in solution.tours.iter().enumerate() {
+            let title = format!("Salesman #{}", i + 1).bright_cyan().bold();
+            if tour.len() < 2 {
+                println!("{} (tidak bertugas)", title);
+                println!();
+                continue;
+            }
+
+            println!("{} (biaya {}, {} kota)", title, solution.tour_costs[i], tour.len() - 2);
+            Self::display_journey_details(graph, tour, solution.tour_costs[i]);
+        }
+
+        println!("{}", solution.stats);
+    }
+
+    /// Per-vehicle load and cost plus the fleet total
+    pub fn display_cvrp_solution(graph: &Graph, solution: &CVRPSolution) {
+        println!("{}", "=== SOLUSI CVRP ===".bright_blue().bold());
+        println!("Depot: {}", graph.node_names[solution.depot].bright_cyan());
+        println!("Kapasitas Kendaraan: {}", solution.capacity.to_string().bright_green());
+        println!("Status: {}", 
+                if solution.is_valid { 
+                    "[VALID]".bright_green() 
+                } else { 
+                    "[INVALID]".bright_red() 
+                });
+        for issue in &solution.issues {
+            println!("  {} {}", "[ERROR]".bright_red(), issue);
+        }
+        println!();
+
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("Kendaraan").style_spec("Fb"),
+            Cell::new("Rute").style_spec("Fb"),
+            Cell::new("Muatan").style_spec("Fb"),
+            Cell::new("Biaya").style_spec("Fb"),
+        ]));
+
+        for (i, route) in solution.routes.iter().enumerate() {
+            let path_str = route.path
+                .iter()
+                .map(|&v| graph.node_names[v].as_str())
+                .collect::<Vec<_>>()
+                .join(" → ");
+
+            table.add_row(Row::new(vec![
+                Cell::new(&(i + 1).to_string()),
+                Cell::new(&path_str),
+                Cell::new(&format!("{}/{}", route.load, solution.capacity))
+                    .style_spec(if route.load > solution.capacity { "Fr" } else { "Fg" }),
+                Cell::new(&route.cost.to_string()),
+            ]));
+        }
+
+        let total_load: i32 = solution.routes.iter().map(|route| route.load).sum();
+        table.add_row(Row::new(vec![
+            Cell::new("ARMADA").style_spec("Fb"),
+            Cell::new(&format!("{} kendaraan", solution.routes.len())),
+            Cell::new(&total_load.to_string()).style_spec("Fb"),
+            Cell::new(&solution.total_cost.to_string()).style_spec("Fg"),
+        ]));
+
+        table.printstd();
+        println!();
+        println!("{}", solution.stats);
+    }
+
+    /// Selective tour (orienteering / prize-collecting) with the list of skipped cities
+    pub fn display_selective_solution(graph: &Graph, solution: &SelectiveTourSolution, rewards: &[CityReward]) {
+        println!("{}", "=== SOLUSI TOUR SELEKTIF ===".bright_blue().bold());
+        println!("Metode: {}", if solution.is_exact { "DP Eksak" } else { "Heuristik" });
+        match solution.mode {
+            SelectiveMode::Orienteering { budget } => 
+                println!("Mode: orienteering (anggaran jarak {})", budget),
+            SelectiveMode::PrizeCollecting => 
+                println!("Mode: prize-collecting TSP"),
+        }
+        println!("Status: {}", 
+                if solution.is_valid { 
+                    "[VALID]".bright_green() 
+                } else { 
+                    "[INVALID]".bright_red() 
+                });
+        println!("{} {}", 
+                "Biaya Tour:".bright_yellow().bold(), 
+                solution.tour_cost.to_string().bright_green().bold());
+        println!("{} {}", 
+                "Hadiah Terkumpul:".bright_yellow().bold(), 
+                solution.collected_prize.to_string().bright_green());
+        println!("{} {}", 
+                "Penalti Dibayar:".bright_yellow().bold(), 
+                solution.penalty_paid.to_string().bright_red());
+        println!();
+
+        if solution.path.len() >= 2 {
+            Self::display_journey_details(graph, &solution.path, solution.tour_cost);
+        } else {
+            println!("Tidak ada kota yang dikunjungi selain {}", graph.node_names[solution.path[0]].bright_cyan());
+            println!();
+        }
+
+        println!("{}", "Kota yang Dilewati:".bright_yellow().bold());
+        if solution.skipped.is_empty() {
+            println!("  (tidak ada)");
+        }
+        for &city in &solution.skipped {
+            println!("  - {} (hadiah {}, penalti {})", 
+                    graph.node_names[city].bright_cyan(), 
+                    rewards[city].prize, 
+                    rewards[city].penalty);
+        }
+        println!();
+        println!("{}", solution.stats);
+    }
+
+    pub fn display_generalized_solution(graph: &Graph, solution: &GTSPSolution) {
+        println!("{}", "=== SOLUSI GENERALIZED TSP ===".bright_blue().bold());
+        println!("Metode: {}", if solution.is_exact { "DP Eksak" } else { "Heuristik" });
+        println!("Jumlah Cluster: {}", solution.clusters.len().to_string().bright_green());
+        println!("Status: {}", 
+                if solution.is_valid { 
+                    "[VALID]".bright_green() 
+                } else { 
+                    "[INVALID]".bright_red() 
+                });
+        println!("{} {}", 
+                "Total Biaya:".bright_yellow().bold(), 
+                solution.cost.to_string().bright_green().bold());
+        println!();
+
+        Self::display_journey_details(graph, &solution.path, solution.cost);
+
+        println!("{}", "Pilihan per Cluster:".bright_yellow().bold());
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("Cluster").style_spec("Fb"),
+            Cell::new("Kota Terpilih").style_spec("Fb"),
+            Cell::new("Alternatif").style_spec("Fb"),
+        ]));
+
+        for (cluster, chosen) in solution.clusters.iter().zip(solution.chosen_cities()) {
+            let alternatives: Vec<&str> = cluster.cities.iter()
+                .filter(|&&city| Some(city) != chosen)
+                .map(|&city| graph.node_names[city].as_str())
+                .collect();
+
+            table.add_row(Row::new(vec![
+                Cell::new(&cluster.name),
+                Cell::new(chosen.map_or("-", |city| graph.node_names[city].as_str())).style_spec("Fg"),
+                Cell::new(&if alternatives.is_empty() { "-".to_string() } else { alternatives.join(", ") }),
+            ]));
+        }
+
+        table.printstd();
+        println!();
+        println!("{}", solution.stats);
+    }
+
+    /// Tour on the metric closure: visited cities, then every leg with its pass-through cities
+    pub fn display_closure_solution(graph: &Graph, route: &ClosureSolution) {
+        let solution = &route.solution;
+        println!("{}", "=== SOLUSI TSP (METRIC CLOSURE) ===".bright_blue().bold());
+        println!("Graf: {} kota", graph.size.to_string().bright_green());
+        println!("Status: {}", 
+                if solution.is_valid { 
+                    "[VALID]".bright_green() 
+                } else { 
+                    "[INVALID]".bright_red() 
+                });
+        for issue in &solution.verification.issues {
+            println!("  {} {}", "[ERROR]".bright_red(), issue);
+        }
+        println!();
+
+        let names = |path: &[usize]| path.iter()
+            .map(|&i| graph.node_names[i].bright_cyan().to_string())
+            .collect::<Vec<_>>()
+            .join(" → ");
+        println!("{}", "Kota yang Dikunjungi:".bright_yellow().bold());
+        println!("  {}", names(&solution.optimal_path));
+        println!("{}", "Rute Lengkap (termasuk kota yang dilewati):".bright_yellow().bold());
+        println!("  {}", names(&route.expanded_path()));
+        println!();
+
+        println!("{} {}", 
+                "Nilai Objektif:".bright_yellow().bold(), 
+                solution.objective_value().to_string().bright_green().bold());
+        println!("{} {}", 
+                "Biaya Total:".bright_yellow().bold(), 
+                solution.optimal_cost.to_string().bright_green());
+        println!();
+
+        println!("{}", "Detail Perjalanan:".bright_yellow().bold());
+        let mut table = Table::new();
+        table.add_row(Row::new(vec![
+            Cell::new("Step").style_spec("Fb"),
+            Cell::new("Dari").style_spec("Fb"),
+            Cell::new("Ke").style_spec("Fb"),
+            Cell::new("Lewat").style_spec("Fb"),
+            Cell::new("Jarak").style_spec("Fb"),
+            Cell::new("Kumulatif").style_spec("Fb"),
+        ]));
+
+        let mut cumulative_cost = 0;
+        for (i, leg) in route.legs.iter().enumerate() {
+            cumulative_cost += leg.cost;
+            let via: Vec<&str> = leg.via.iter().map(|&city| graph.node_names[city].as_str()).collect();
+
+            table.add_row(Row::new(vec![
+                Cell::new(&(i + 1).to_string()),
+                Cell::new(&graph.node_names[leg.from]),
+                Cell::new(&graph.node_names[leg.to]),
+                Cell::new(&if via.is_empty() { "-".to_string() } else { via.join(" → ") }).style_spec("Fy"),
+                Cell::new(&leg.cost.to_string()).style_spec("Fr"),
+                Cell::new(&cumulative_cost.to_string()).style_spec("Fg"),
+            ]));
+        }
+
+        table.add_row(Row::new(vec![
+            Cell::new("TOTAL").style_spec("Fb"),
+            Cell::new(""),
+            Cell::new(""),
+            Cell::new(""),
+            Cell::new(""),
+            Cell::new(&solution.optimal_cost.to_string()).style_spec("FgB"),
+        ]));
+
+        table.printstd();
+        println!();
+        println!("{}", solution.stats);
+    }
+
+    pub fn display_pareto_front(graph: &Graph, front: &ParetoFront) {
+        println!("{}", "=== FRONT PARETO ===".bright_blue().bold());
+        println!("Metode: {}", if front.is_exact { "DP Eksak" } else { "Heuristik (aproksimasi)" });
+        println!("Kriteria: {}", front.criteria.join(", ").bright_cyan());
+        println!("Jumlah Tour Non-dominated: {}", front.tours.len().to_string().bright_green());
+        println!();
+
+        let mut table = Table::new();
+        let mut header = vec![Cell::new("No").style_spec("Fb")];
+        header.extend(front.criteria.iter().map(|name| Cell::new(name).style_spec("Fb")));
+        header.push(Cell::new("Rute").style_spec("Fb"));
+        table.add_row(Row::new(header));
+
+        for (index, tour) in front.tours.iter().enumerate() {
+            let mut row = vec![Cell::new(&(index + 1).to_string())];
+            row.extend(tour.costs.iter().map(|cost| Cell::new(&cost.to_string()).style_spec("Fg")));
+            row.push(Cell::new(&tour.path.iter()
+                .map(|&city| graph.node_names[city].as_str())
+                .collect::<Vec<_>>()
+                .join(" → ")));
+            table.add_row(Row::new(row));
+        }
+
+        table.printstd();
+        println!();
+        println!("{}", front.stats);
+    }
+
     pub fn display_comparison(solutions: &[(&str, &TSPSolution)]) {
         if solutions.len() < 2 {
             return;
@@ -144,24 +942,22 @@ impl Visualizer {
         let mut table = Table::new();
         table.add_row(Row::new(vec![
             Cell::new("Metode").style_spec("Fb"),
-            Cell::new("Biaya").style_spec("Fb"),
+            Cell::new("Objektif").style_spec("Fb"),
+            Cell::new("Nilai").style_spec("Fb"),
             Cell::new("Waktu").style_spec("Fb"),
             Cell::new("States").style_spec("Fb"),
             Cell::new("Valid").style_spec("Fb"),
         ]));
 
-        let mut best_cost = i32::MAX;
-        for (_, solution) in solutions {
-            if solution.optimal_cost < best_cost {
-                best_cost = solution.optimal_cost;
-            }
-        }
+        // Each value is the total length, or the longest edge for bottleneck
+        let best_key = solutions.iter().map(|(_, solution)| solution.objective_key()).min();
 
         for (name, solution) in solutions {
-            let cost_cell = if solution.optimal_cost == best_cost {
-                Cell::new(&format!("{} [BEST]", solution.optimal_cost)).style_spec("FgB")
+            let value = solution.objective_value();
+            let cost_cell = if Some(solution.objective_key()) == best_key {
+                Cell::new(&format!("{} [BEST]", value)).style_spec("FgB")
             } else {
-                Cell::new(&solution.optimal_cost.to_string())
+                Cell::new(&value.to_string())
             };
 
             let time_str = solution.stats.solve_duration
@@ -172,6 +968,7 @@ impl Visualizer {
 
             table.add_row(Row::new(vec![
                 Cell::new(name),
+                Cell::new(Self::objective_label(solution.objective)),
                 cost_cell,
                 Cell::new(&time_str),
                 Cell::new(&solution.stats.states_computed.to_string()),
@@ -194,12 +991,13 @@ impl Visualizer {
 
         let best_solution = solutions
             .iter()
-            .min_by_key(|(_, sol)| sol.optimal_cost)
+            .min_by_key(|(_, sol)| sol.objective_key())
             .unwrap();
 
-        println!("[BEST] {}: {}", 
+        println!("[BEST] {}: {} ({})", 
                 best_solution.0.bright_cyan(),
-                best_solution.1.optimal_cost.to_string().bright_green());
+                best_solution.1.objective_value().to_string().bright_green(),
+                Self::objective_label(best_solution.1.objective));
 
         if let Some(duration) = best_solution.1.stats.solve_duration {
             println!("Time: {:.2?}", duration);